@@ -0,0 +1,291 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! A persistent on-disk cache of the mutant test results.
+//!
+//! Each result is stored in a separate file named after the hash of all inputs which can affect
+//! the result: the mutant source code, the path of the mutated file, the sources of the package and
+//! of its dependencies, the package manifests and the test configuration. When none of them changes,
+//! the result can be reused instead of running the tests on the mutant again.
+
+use crate::cli::TestBuildConfig;
+use mutator_common::{hash::stable_hash, report::MutantStatus};
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The package manifest file.
+const MANIFEST_FILE: &str = "Move.toml";
+/// The directory with the build artifacts, which are not inputs of the tests.
+const BUILD_DIR: &str = "build";
+/// The extension of the Move source files.
+const MOVE_EXTENSION: &str = "move";
+
+/// The cached result of testing a single mutant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CachedResult {
+    /// The status of the mutant.
+    pub status: MutantStatus,
+    /// The tests which killed the mutant.
+    #[serde(default)]
+    pub killed_by: Vec<String>,
+}
+
+/// A content-addressed cache of the mutant test results.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    /// The directory where the results are stored.
+    dir: PathBuf,
+    /// The hash of the inputs shared by all mutants.
+    common_hash: String,
+}
+
+impl ResultCache {
+    /// Opens the cache in the given directory, creating it if needed.
+    ///
+    /// The hash of the sources and the manifests is computed from the `package_dirs`, which are the
+    /// directories of the tested package and of all its resolved dependencies.
+    pub(crate) fn new(
+        dir: &Path,
+        package_dirs: &[PathBuf],
+        test_config: &TestBuildConfig,
+    ) -> anyhow::Result<Self> {
+        fs::create_dir_all(dir)?;
+
+        // Results produced by another version of the tool might differ.
+        let mut fields = vec![env!("CARGO_PKG_VERSION").as_bytes().to_vec()];
+        fields.extend(config_fields(test_config));
+        for package_dir in package_dirs {
+            hash_dir(package_dir, package_dir, &mut fields)?;
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            common_hash: stable_hash(fields.iter().map(Vec::as_slice)),
+        })
+    }
+
+    /// Computes the key of the mutant with the given source code, which replaces `original_file`.
+    pub(crate) fn key(&self, original_file: &Path, mutant_source: &[u8]) -> String {
+        stable_hash([
+            self.common_hash.as_bytes(),
+            original_file.to_string_lossy().as_bytes(),
+            mutant_source,
+        ])
+    }
+
+    /// Returns the cached result for the given key, if any.
+    pub(crate) fn get(&self, key: &str) -> Option<CachedResult> {
+        let content = fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str(&content)
+            .map_err(|e| warn!("ignoring invalid cache entry {key}: {e}"))
+            .ok()
+    }
+
+    /// Stores the result for the given key.
+    ///
+    /// Results which don't tell anything about the mutant (tool errors or skipped mutants) or
+    /// depend on the machine load (timeouts) are not stored, so they are retried during the next run.
+    pub(crate) fn insert(&self, key: &str, result: &CachedResult) -> anyhow::Result<()> {
+        if matches!(
+            result.status,
+            MutantStatus::ToolError | MutantStatus::Skipped | MutantStatus::Timeout
+        ) {
+            return Ok(());
+        }
+
+        // Write to a temporary file first to never leave a partially written entry behind.
+        let path = self.entry_path(key);
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec(result)?)?;
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+/// Returns the options of the test configuration which can affect the test results.
+///
+/// The options are hashed explicitly, since their `Debug` output isn't stable across the
+/// toolchain and dependency versions. The package location is not relevant as the package is
+/// always copied before testing, and neither are the options overridden for the mutants, like
+/// the coverage or the compiler warnings.
+fn config_fields(config: &TestBuildConfig) -> Vec<Vec<u8>> {
+    fn optional<T: ToString>(value: Option<T>) -> String {
+        value.map(|v| v.to_string()).unwrap_or_default()
+    }
+
+    let compiler_config = config.compiler_config();
+    let named_addresses = config
+        .move_options
+        .named_addresses()
+        .iter()
+        .map(|(name, address)| format!("{name}={}", address.to_hex_literal()))
+        .collect::<Vec<_>>();
+
+    [
+        optional(config.filter.as_ref()),
+        config.gas_limit.to_string(),
+        config.fail_fast.to_string(),
+        config.move_options.dev.to_string(),
+        config.move_options.skip_checks_on_test_code.to_string(),
+        named_addresses.join(","),
+        optional(compiler_config.compiler_version),
+        optional(compiler_config.language_version),
+        optional(compiler_config.bytecode_version),
+        compiler_config.experiments.join(","),
+    ]
+    .into_iter()
+    .map(String::into_bytes)
+    .collect()
+}
+
+/// Adds the relative paths and the contents of the manifest and the Move source files inside
+/// the directory to the hashed fields, in a deterministic order.
+///
+/// The build artifacts and the hidden directories (e.g. the cache itself) are skipped.
+fn hash_dir(dir: &Path, root: &Path, fields: &mut Vec<Vec<u8>>) -> anyhow::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let name = entry.file_name().unwrap_or_default();
+        if entry.is_dir() {
+            if name != BUILD_DIR && !name.to_string_lossy().starts_with('.') {
+                hash_dir(&entry, root, fields)?;
+            }
+        } else if name == MANIFEST_FILE || entry.extension() == Some(OsStr::new(MOVE_EXTENSION)) {
+            let relative_path = entry.strip_prefix(root).unwrap_or(&entry);
+            fields.push(relative_path.to_string_lossy().into_owned().into_bytes());
+            fields.push(fs::read(&entry)?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::tempdir;
+
+    fn test_config() -> TestBuildConfig {
+        TestBuildConfig::parse_from(["test"])
+    }
+
+    #[test]
+    fn results_are_reused_only_for_the_same_inputs() {
+        let package = tempdir().unwrap();
+        let dependency = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let package_dirs = [
+            package.path().to_path_buf(),
+            dependency.path().to_path_buf(),
+        ];
+        fs::write(package.path().join(MANIFEST_FILE), "[package]").unwrap();
+        fs::create_dir(package.path().join("sources")).unwrap();
+        fs::write(
+            package.path().join("sources/Sum.move"),
+            "module 0x1::Sum {}",
+        )
+        .unwrap();
+
+        let cache = ResultCache::new(cache_dir.path(), &package_dirs, &test_config()).unwrap();
+        let key = cache.key(Path::new("sources/Sum.move"), b"module 0x1::Sum {}");
+        assert!(cache.get(&key).is_none());
+
+        let result = CachedResult {
+            status: MutantStatus::KilledByTest,
+            killed_by: vec!["0x1::Sum::sum_test".to_owned()],
+        };
+        cache.insert(&key, &result).unwrap();
+        assert_eq!(cache.get(&key), Some(result.clone()));
+
+        // Another mutant of the same file.
+        assert_ne!(
+            key,
+            cache.key(Path::new("sources/Sum.move"), b"module 0x1::Sum { }")
+        );
+
+        // The build artifacts and the files other than the sources don't invalidate the results.
+        fs::create_dir(package.path().join(BUILD_DIR)).unwrap();
+        fs::write(package.path().join(BUILD_DIR).join("Sum.move"), "").unwrap();
+        fs::write(package.path().join("README.md"), "").unwrap();
+        let cache = ResultCache::new(cache_dir.path(), &package_dirs, &test_config()).unwrap();
+        assert_eq!(cache.get(&key), Some(result));
+
+        // Changed sources of another module invalidate all results.
+        fs::write(package.path().join("sources/Other.move"), "").unwrap();
+        let cache = ResultCache::new(cache_dir.path(), &package_dirs, &test_config()).unwrap();
+        let key = cache.key(Path::new("sources/Sum.move"), b"module 0x1::Sum {}");
+        assert!(cache.get(&key).is_none());
+
+        // So do the changed sources of the dependencies.
+        cache
+            .insert(&key, &CachedResult {
+                status: MutantStatus::Alive,
+                killed_by: vec![],
+            })
+            .unwrap();
+        fs::write(dependency.path().join("Dep.move"), "").unwrap();
+        let cache = ResultCache::new(cache_dir.path(), &package_dirs, &test_config()).unwrap();
+        assert!(cache
+            .get(&cache.key(Path::new("sources/Sum.move"), b"module 0x1::Sum {}"))
+            .is_none());
+    }
+
+    #[test]
+    fn results_depend_on_the_test_options() {
+        let package = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let package_dirs = [package.path().to_path_buf()];
+        let key = |config: &TestBuildConfig| {
+            ResultCache::new(cache_dir.path(), &package_dirs, config)
+                .unwrap()
+                .key(Path::new("sources/Sum.move"), b"module 0x1::Sum {}")
+        };
+
+        // The package location and the options overridden for the mutants don't matter.
+        let mut config = test_config();
+        config.move_options.package_dir = Some(package.path().to_path_buf());
+        config.compute_coverage = true;
+        config.ignore_compile_warnings = true;
+        assert_eq!(key(&config), key(&test_config()));
+
+        let config = TestBuildConfig::parse_from(["test", "--gas-limit", "10"]);
+        assert_ne!(key(&config), key(&test_config()));
+
+        let config = TestBuildConfig::parse_from(["test", "--filter", "sum"]);
+        assert_ne!(key(&config), key(&test_config()));
+    }
+
+    #[test]
+    fn tool_errors_are_not_cached() {
+        let package = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+
+        let cache = ResultCache::new(
+            cache_dir.path(),
+            &[package.path().to_path_buf()],
+            &test_config(),
+        )
+        .unwrap();
+        let key = cache.key(Path::new("sources/Sum.move"), b"");
+        cache
+            .insert(&key, &CachedResult {
+                status: MutantStatus::ToolError,
+                killed_by: vec![],
+            })
+            .unwrap();
+        assert!(cache.get(&key).is_none());
+    }
+}
@@ -8,28 +8,42 @@ use clap::Parser;
 use move_model::metadata::{CompilerVersion, LanguageVersion};
 use move_mutator::cli::{FunctionFilter, ModuleFilter, OperatorModeArg};
 use move_package::CompilerConfig;
-use std::path::PathBuf;
+use mutator_common::benchmark::MutantTimeout;
+use std::{path::PathBuf, time::Duration};
 
 /// Command line options for mutation test tool.
 #[derive(Parser, Default, Debug, Clone)]
 pub struct CLIOptions {
-    /// Work only over specified modules.
-    #[clap(
-        long,
-        value_parser,
-        default_value = "all",
-        conflicts_with = "use_generated_mutants"
-    )]
-    pub mutate_modules: ModuleFilter,
+    /// Work only over specified modules (names or glob patterns, e.g. `pool*`).
+    ///
+    /// The constants of the selected modules are mutated as well, although they aren't covered
+    /// by the tests coverage.
+    ///
+    /// All modules are mutated by default.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub mutate_modules: Option<ModuleFilter>,
 
-    /// Work only over specified functions (these are not qualified functions).
-    #[clap(
-        long,
-        value_parser,
-        default_value = "all",
-        conflicts_with = "use_generated_mutants"
-    )]
-    pub mutate_functions: FunctionFilter,
+    /// Skip specified modules (names or glob patterns).
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub exclude_modules: Option<ModuleFilter>,
+
+    /// Work only over specified functions (names or glob patterns, optionally qualified with
+    /// the module, e.g. `pool::swap_*`).
+    ///
+    /// All functions are mutated by default.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub mutate_functions: Option<FunctionFilter>,
+
+    /// Skip specified functions (names or glob patterns, optionally qualified with the module).
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub exclude_functions: Option<FunctionFilter>,
+
+    /// Optional configuration file for mutator tool (TOML, or JSON with the `.json` extension).
+    ///
+    /// The options given on the command line take precedence over the ones from the file. The
+    /// mutants are kept in the output directory of this tool, so `out_mutant_dir` can't be set.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub mutator_conf: Option<PathBuf>,
 
     /// Save report to a JSON file.
     #[clap(long, value_parser)]
@@ -48,7 +62,7 @@ pub struct CLIOptions {
     /// - light: binary_operator_swap, break_continue_replacement, delete_statement
     /// - medium: light + literal_replacement
     /// - medium-only: literal_replacement (only what's added in medium)
-    /// - heavy (default): all 7 operators
+    /// - heavy (default): all 7 ranked operators
     /// - heavy-only: unary_operator_replacement, binary_operator_replacement, if_else_replacement (only what's added in heavy)
     #[clap(
         long,
@@ -60,7 +74,10 @@ pub struct CLIOptions {
 
     /// Custom operator selection to run mutations on (comma-separated).
     ///
-    /// Available operators: unary_operator_replacement, delete_statement, break_continue_replacement, binary_operator_replacement, if_else_replacement, literal_replacement, binary_operator_swap
+    /// Available operators: unary_operator_replacement, delete_statement, break_continue_replacement, binary_operator_replacement, if_else_replacement, literal_replacement, binary_operator_swap, return_value_replacement, assert_replacement, abort_code_replacement
+    ///
+    /// The return_value_replacement, assert_replacement and abort_code_replacement operators are
+    /// not included in any mode, they are applied only when listed here.
     #[clap(
         long,
         value_parser,
@@ -69,6 +86,62 @@ pub struct CLIOptions {
         conflicts_with = "use_generated_mutants"
     )]
     pub operators: Option<Vec<String>>,
+
+    /// Compile every generated mutant and discard the ones which do not compile.
+    #[clap(long, conflicts_with = "use_generated_mutants")]
+    pub verify_mutants: bool,
+
+    /// Compile every generated mutant and drop the ones with the same bytecode as the original code.
+    #[clap(long, conflicts_with = "use_generated_mutants")]
+    pub check_equivalence: bool,
+
+    /// Cache the mutant test results in the given directory and reuse them in the next runs.
+    ///
+    /// A result is reused only if the mutant, the sources of the package and of its dependencies,
+    /// the manifests and the test configuration haven't changed since it was stored.
+    #[clap(long, value_parser)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Keep the generated mutants and the checkpoint file with the results in the given directory,
+    /// so the run can be continued with `--resume` if it's interrupted.
+    #[clap(
+        long,
+        value_parser,
+        conflicts_with_all = ["use_generated_mutants", "resume"]
+    )]
+    pub checkpoint_dir: Option<PathBuf>,
+
+    /// Resume an interrupted run from its checkpoint directory.
+    ///
+    /// The mutants generated by the interrupted run are reused and the ones already recorded in
+    /// its checkpoint file are not tested again.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub resume: Option<PathBuf>,
+
+    /// Abort the tests of a mutant after the given number of seconds and mark it as timed out.
+    ///
+    /// With `auto`, the limit is a multiple of the time the tests took on the original code.
+    /// The aborted tests keep running in the background until they finish, as they cannot be
+    /// stopped. There's no limit by default.
+    #[clap(long, value_parser)]
+    pub mutant_timeout: Option<MutantTimeout>,
+
+    /// Triage file with the reviewed surviving mutants, which are excluded from the score.
+    ///
+    /// The default file is `mutation-triage.toml` in the package directory, if it exists.
+    #[clap(long, value_parser)]
+    pub triage: Option<PathBuf>,
+}
+
+impl CLIOptions {
+    /// Returns the time limit for testing a single mutant, if any.
+    ///
+    /// The `auto` limit is derived from the `original_duration` of the tests on the original code.
+    #[must_use]
+    pub fn mutant_timeout(&self, original_duration: Duration) -> Option<Duration> {
+        self.mutant_timeout
+            .map(|timeout| timeout.duration(original_duration))
+    }
 }
 
 /// This function creates a mutator CLI options from the given mutation-test options.
@@ -78,12 +151,17 @@ pub fn create_mutator_options(
     apply_coverage: bool,
 ) -> move_mutator::cli::CLIOptions {
     move_mutator::cli::CLIOptions {
+        mutator_conf: options.mutator_conf.clone(),
         mutate_functions: options.mutate_functions.clone(),
         mutate_modules: options.mutate_modules.clone(),
+        exclude_modules: options.exclude_modules.clone(),
+        exclude_functions: options.exclude_functions.clone(),
         downsampling_ratio_percentage: options.downsampling_ratio_percentage,
         apply_coverage,
         mode: options.mode,
         operators: options.operators.clone(),
+        verify_mutants: options.verify_mutants,
+        check_equivalence: options.check_equivalence,
         ..Default::default()
     }
 }
@@ -162,24 +240,48 @@ mod tests {
     #[test]
     fn cli_options_starts_empty() {
         let options = CLIOptions::default();
-        assert_eq!(ModuleFilter::All, options.mutate_modules);
-        assert_eq!(FunctionFilter::All, options.mutate_functions);
+        assert!(options.mutate_modules.is_none());
+        assert!(options.mutate_functions.is_none());
         assert!(options.output.is_none());
     }
 
     #[test]
     fn create_mutator_options_copies_fields() {
         let options = crate::cli::CLIOptions {
-            mutate_modules: ModuleFilter::Selected(vec!["mod1".to_string(), "mod2".to_string()]),
-            mutate_functions: FunctionFilter::Selected(vec![
+            mutate_modules: Some(ModuleFilter::Selected(vec![
+                "mod1".to_string(),
+                "mod2".to_string(),
+            ])),
+            mutate_functions: Some(FunctionFilter::Selected(vec![
                 "func1".to_string(),
                 "func2".to_string(),
-            ]),
+            ])),
+            mutator_conf: Some(PathBuf::from("mutator.toml")),
             ..Default::default()
         };
 
         let mutator_options = create_mutator_options(&options, false);
 
         assert_eq!(mutator_options.mutate_modules, options.mutate_modules);
+        assert_eq!(mutator_options.mutator_conf, options.mutator_conf);
+    }
+
+    #[test]
+    fn mutant_timeout_is_opt_in() {
+        let original_duration = Duration::from_secs(1000);
+
+        let options = CLIOptions::default();
+        assert!(options.mutant_timeout(original_duration).is_none());
+
+        let options = CLIOptions::parse_from(["test", "--mutant-timeout", "auto"]);
+        assert!(options.mutant_timeout(original_duration).unwrap() >= original_duration);
+
+        let options = CLIOptions::parse_from(["test", "--mutant-timeout", "10"]);
+        assert_eq!(
+            options.mutant_timeout(original_duration),
+            Some(Duration::from_secs(10))
+        );
+
+        assert!(CLIOptions::try_parse_from(["test", "--mutant-timeout", "0"]).is_err());
     }
 }
@@ -2,6 +2,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+mod cache;
 pub mod cli;
 mod mutation_test;
 
@@ -9,20 +10,32 @@ extern crate pretty_env_logger;
 #[macro_use]
 extern crate log;
 
-use crate::mutation_test::{run_tests_on_mutated_code, run_tests_on_original_code};
+use crate::{
+    cache::{CachedResult, ResultCache},
+    mutation_test::{package_dirs, run_tests_on_mutated_code, run_tests_on_original_code},
+};
 use cli::TestBuildConfig;
 use fs_extra::dir::CopyOptions;
 use indicatif::{ProgressBar, ProgressStyle};
+use move_mutator::configuration::MutatorConfigFile;
 use move_package::BuildConfig;
 use mutator_common::{
-    benchmark::{Benchmark, Benchmarks},
+    benchmark::{wait_for_abandoned_threads, Benchmark, Benchmarks},
+    checkpoint::{create_checkpoint_dir, load_checkpoint, CheckpointWriter},
+    interrupt::{install_handler, is_interrupted},
     report::{MiniReport, MutantStatus, Report},
-    tmp_package_dir::{setup_outdir_and_package_path, strip_path_prefix},
+    tmp_package_dir::{cleanup_outdir, setup_outdir_and_package_path, strip_path_prefix},
+    triage::apply_triage_file,
 };
 use rayon::prelude::*;
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
 };
 
 /// This function runs the mutation testing, which is a combination of the mutator tool and the test tool.
@@ -51,6 +64,19 @@ pub fn run_mutation_test(
     // We need to initialize logger using try_init() as it might be already initialized in some other tool
     // (e.g. move-mutator). If we use init() instead, we will get an abort.
     let _ = pretty_env_logger::try_init();
+    install_handler()?;
+
+    // The mutants are always kept in the output directory of this tool.
+    if let Some(path) = &options.mutator_conf {
+        MutatorConfigFile::reject_out_mutant_dir(path)?;
+    }
+
+    // Results are recorded as soon as they are known, so an interrupted run can be resumed.
+    let checkpoint_dir = match (&options.resume, &options.checkpoint_dir) {
+        (Some(dir), _) => Some(dir.canonicalize()?),
+        (None, Some(dir)) => Some(create_checkpoint_dir(dir)?),
+        (None, None) => None,
+    };
 
     // Setup output dir and clone package path there.
     let original_package_path = test_config
@@ -69,12 +95,17 @@ pub fn run_mutation_test(
 
     // Run original tests to ensure the original tests are working:
     benchmarks.executing_original_package.start();
-    run_tests_on_original_code(test_config, &package_path)?;
+    let tests = run_tests_on_original_code(test_config, &package_path)?;
     benchmarks.executing_original_package.stop();
 
+    let mutant_timeout = options.mutant_timeout(benchmarks.executing_original_package.elapsed);
+    info!("Using the time limit for testing a single mutant: {mutant_timeout:?}");
+
     // Create mutants:
     let outdir_mutant = if let Some(mutant_path) = &options.use_generated_mutants {
         mutant_path.clone()
+    } else if let Some(resume_dir) = &options.resume {
+        resume_dir.join("mutants")
     } else {
         benchmarks.mutator.start();
         let mutator_config = BuildConfig {
@@ -91,7 +122,8 @@ pub fn run_mutation_test(
             test_config.compute_coverage,
             &mutator_config,
             &package_path,
-            &outdir,
+            // The mutants are kept with the checkpoint, so the run can be resumed.
+            checkpoint_dir.as_deref().unwrap_or(&outdir),
         )?;
         benchmarks.mutator.stop();
         outdir_mutant
@@ -100,11 +132,51 @@ pub fn run_mutation_test(
     let report =
         move_mutator::report::Report::load_from_json_file(&outdir_mutant.join("report.json"))?;
 
+    let cache = options
+        .cache_dir
+        .as_ref()
+        .map(|dir| {
+            let package_dirs = package_dirs(test_config, &package_path)?;
+            ResultCache::new(dir, &package_dirs, test_config)
+        })
+        .transpose()?;
+    let cached_results = AtomicUsize::new(0);
+    // Packages of the timed out mutants, which might still be used by the abandoned tests.
+    let abandoned_dirs = Mutex::new(Vec::new());
+
     // Run tests on mutants:
     benchmarks.executing_tests_on_mutants.start();
     let cp_opts = CopyOptions::new().content_only(true);
 
-    let mutants = report.get_mutants();
+    let (mut finished, checkpoint) = match &checkpoint_dir {
+        Some(dir) => {
+            let finished = load_checkpoint(dir)?;
+            let checkpoint = CheckpointWriter::open(dir)?;
+            println!(
+                "Saving results to {} (use `--resume {}` to continue an interrupted run)",
+                checkpoint.path().display(),
+                dir.display()
+            );
+            (finished, Some(checkpoint))
+        },
+        None => (HashMap::new(), None),
+    };
+
+    let mut mini_reports = Vec::<MiniReport>::with_capacity(report.get_mutants().len());
+    let mut mutants = Vec::with_capacity(report.get_mutants().len());
+    for elem in report.get_mutants() {
+        match finished.remove(elem.mutant_path()) {
+            Some(mini_report) => mini_reports.push(mini_report),
+            None => mutants.push(elem),
+        }
+    }
+    if !mini_reports.is_empty() {
+        println!(
+            "Skipping {} mutants already tested in the resumed run",
+            mini_reports.len()
+        );
+    }
+
     println!("\nRunning tests on {} mutants\n", mutants.len());
 
     let total = mutants.len() as u64;
@@ -120,7 +192,6 @@ pub fn run_mutation_test(
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
     let mut mutation_test_benchmarks = Vec::<Benchmark>::with_capacity(mutants.len());
-    let mut mini_reports = Vec::<MiniReport>::with_capacity(mutants.len());
     //  Split mutants into chunks before applying rayon threads, as trying to process them all in
     //  one go can lead to memory starvation if the number of mutants is too huge to handle.
     const CHUNK_SIZE: usize = 64;
@@ -144,33 +215,83 @@ pub fn run_mutation_test(
                 let original_file =
                     strip_path_prefix(elem.original_file_path()).expect("invalid package path");
 
-                let job_outdir = outdir.join(format!("mutation_test_{rayon_tid}"));
-                let _ = fs::remove_dir_all(&job_outdir);
-
-                fs_extra::dir::copy(&package_path, &job_outdir, &cp_opts)
-                    .expect("copying directory failed");
-
-                trace!(
-                    "Copying mutant file {} to the package directory {:?}",
-                    mutant_file.display(),
-                    job_outdir.join(&original_file)
-                );
-                // Should never fail, since files will always exists.
-                fs::copy(mutant_file, job_outdir.join(&original_file))
-                    .expect("copying file failed");
-
-                benchmark.start();
-                let result = run_tests_on_mutated_code(test_config, &job_outdir);
-                benchmark.stop();
-
-                let mutant_status = if let Err(e) = result {
-                    trace!("Mutant killed! Unit test failed with error: {e}");
-                    MutantStatus::Killed
+                let cache_key = cache.as_ref().map(|cache| {
+                    // Should never fail, since files will always exists.
+                    let mutant_source = fs::read(mutant_file).expect("reading file failed");
+                    cache.key(&original_file, &mutant_source)
+                });
+                let cached = cache
+                    .as_ref()
+                    .zip(cache_key.as_deref())
+                    .and_then(|(cache, key)| cache.get(key));
+
+                let (mutant_status, killed_by) = if is_interrupted() {
+                    // Don't start testing new mutants once the run is interrupted.
+                    (MutantStatus::Skipped, vec![])
+                } else if let Some(cached) = cached {
+                    info!(
+                        "job_{rayon_tid}: Reusing the cached result for mutant {}",
+                        mutant_file.display()
+                    );
+                    cached_results.fetch_add(1, Ordering::Relaxed);
+                    (cached.status, cached.killed_by)
                 } else {
-                    info!("Mutant {} hasn't been killed!", mutant_file.display());
-                    MutantStatus::Alive
+                    let job_outdir = outdir.join(format!("mutation_test_{rayon_tid}"));
+                    let _ = fs::remove_dir_all(&job_outdir);
+
+                    fs_extra::dir::copy(&package_path, &job_outdir, &cp_opts)
+                        .expect("copying directory failed");
+
+                    trace!(
+                        "Copying mutant file {} to the package directory {:?}",
+                        mutant_file.display(),
+                        job_outdir.join(&original_file)
+                    );
+                    // Should never fail, since files will always exists.
+                    fs::copy(mutant_file, job_outdir.join(&original_file))
+                        .expect("copying file failed");
+
+                    benchmark.start();
+                    let (mutant_status, killed_by) =
+                        run_tests_on_mutated_code(test_config, &job_outdir, mutant_timeout);
+                    benchmark.stop();
+
+                    if mutant_status == MutantStatus::Timeout {
+                        // The tests might still be running in the background, so give them
+                        // the directory and let the next mutant use a fresh one.
+                        let stem = mutant_file.file_stem().unwrap_or_default();
+                        let abandoned_dir = outdir.join(format!(
+                            "mutation_test_{rayon_tid}_timeout_{}",
+                            stem.to_string_lossy()
+                        ));
+                        match fs::rename(&job_outdir, &abandoned_dir) {
+                            Ok(()) => abandoned_dirs
+                                .lock()
+                                .expect("abandoned directories lock poisoned")
+                                .push(abandoned_dir),
+                            Err(e) => {
+                                warn!("job_{rayon_tid}: cannot move the timed out package: {e}")
+                            },
+                        }
+                    }
+
+                    if let Some((cache, key)) = cache.as_ref().zip(cache_key.as_deref()) {
+                        let result = CachedResult {
+                            status: mutant_status,
+                            killed_by: killed_by.clone(),
+                        };
+                        if let Err(e) = cache.insert(key, &result) {
+                            warn!("job_{rayon_tid}: cannot cache the result: {e}");
+                        }
+                    }
+
+                    (mutant_status, killed_by)
                 };
 
+                if mutant_status == MutantStatus::Alive {
+                    info!("Mutant {} hasn't been killed!", mutant_file.display());
+                }
+
                 let diff = elem.get_diff().to_owned();
 
                 // Qualified name for the function.
@@ -178,12 +299,23 @@ pub fn run_mutation_test(
                 qname.push_str("::");
                 qname.push_str(elem.get_function_name());
 
+                let mini_report =
+                    MiniReport::new(original_file.to_path_buf(), qname, mutant_status, diff)
+                        .with_killed_by(killed_by)
+                        .with_mutant_id(elem.get_mutant_id().to_owned());
+                // Skipped mutants are not recorded, so they are tested when the run is resumed.
+                if let Some(checkpoint) = checkpoint
+                    .as_ref()
+                    .filter(|_| mutant_status != MutantStatus::Skipped)
+                {
+                    if let Err(e) = checkpoint.append(mutant_file, &mini_report) {
+                        warn!("job_{rayon_tid}: cannot save the result to the checkpoint: {e}");
+                    }
+                }
+
                 pb_handle.inc(1);
 
-                (
-                    benchmark,
-                    MiniReport::new(original_file.to_path_buf(), qname, mutant_status, diff),
-                )
+                (benchmark, mini_report)
             })
             .collect::<Vec<(_, _)>>()
             .into_iter()
@@ -201,27 +333,54 @@ pub fn run_mutation_test(
 
     pb.finish_with_message("Mutation testing done");
 
+    // The abandoned tests cannot be stopped, so their packages are removed once they finish.
+    wait_for_abandoned_threads();
+    for dir in abandoned_dirs
+        .into_inner()
+        .expect("abandoned directories lock poisoned")
+    {
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            warn!("Cannot remove the timed out package {}: {e}", dir.display());
+        }
+    }
+
+    if cache.is_some() {
+        println!(
+            "Reused cached results for {} mutants",
+            cached_results.load(Ordering::Relaxed)
+        );
+    }
+
     benchmarks.executing_tests_on_mutants.stop();
     benchmarks.mutant_results = mutation_test_benchmarks;
 
     // Prepare a report.
     let mut test_report = Report::new(original_package_path);
-    for MiniReport {
-        original_file,
-        qname,
-        mutant_status,
-        diff,
-    } in mini_reports
-    {
-        test_report.increment_mutants_tested(&original_file, &qname);
-        if let MutantStatus::Alive = mutant_status {
-            test_report.add_mutants_alive_diff(&original_file, &qname, &diff);
-        } else {
-            test_report.increment_mutants_killed(&original_file, &qname);
-            test_report.add_mutants_killed_diff(&original_file, &qname, &diff);
-        }
+    test_report.tests = tests.into_iter().collect();
+    test_report.killed_by_complete = !test_config.fail_fast;
+    for mini_report in mini_reports {
+        test_report.add_mutant_result(mini_report);
+    }
+
+    // Mutants equivalent to the original code are not tested, but they are still reported.
+    for elem in report.get_equivalent_mutants() {
+        let original_file =
+            strip_path_prefix(elem.original_file_path()).expect("invalid package path");
+        let qname = format!("{}::{}", elem.get_module_name(), elem.get_function_name());
+        test_report.add_mutant_result(
+            MiniReport::new(
+                original_file.to_path_buf(),
+                qname,
+                MutantStatus::Equivalent,
+                elem.get_diff().to_owned(),
+            )
+            .with_mutant_id(elem.get_mutant_id().to_owned()),
+        );
     }
 
+    // Surviving mutants accepted after a review are not counted.
+    apply_triage_file(&mut test_report, options.triage.as_deref())?;
+
     test_report.print_table();
 
     benchmarks.total_tool_duration.stop();
@@ -233,6 +392,17 @@ pub fn run_mutation_test(
         println!("Report saved to: {}", out.display());
     }
 
+    if is_interrupted() {
+        cleanup_outdir(&outdir)?;
+        let resume_hint = checkpoint_dir
+            .map(|dir| format!(" (use `--resume {}` to test them)", dir.display()))
+            .unwrap_or_default();
+        anyhow::bail!(
+            "Mutation testing was interrupted, {} mutants were skipped{resume_hint}",
+            test_report.mutants_skipped()
+        );
+    }
+
     Ok(())
 }
 
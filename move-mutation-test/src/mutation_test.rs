@@ -11,8 +11,68 @@ use move_cli::base::test::UnitTestResult;
 use move_command_line_common::address::NumericalAddress;
 use move_package::BuildConfig;
 use move_unit_test::UnitTestingConfig;
-use std::{fs, path::Path, thread};
-use termcolor::WriteColor;
+use mutator_common::{
+    benchmark::{run_with_timeout, TimedJob},
+    report::MutantStatus,
+};
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+use termcolor::{ColorSpec, NoColor, WriteColor};
+
+/// The test runner marks the tests which exceeded the gas limit with this marker.
+const TEST_TIMEOUT_MARKER: &str = "[ TIMEOUT ]";
+/// The test runner marks the failing tests with this marker.
+const TEST_FAIL_MARKER: &str = "[ FAIL    ]";
+/// The test runner marks the passing tests with this marker.
+const TEST_PASS_MARKER: &str = "[ PASS    ]";
+
+/// A writer which passes the output to the inner writer and keeps a copy of it.
+struct CapturingWriter<W> {
+    inner: W,
+    captured: Vec<u8>,
+}
+
+impl<W> CapturingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            captured: vec![],
+        }
+    }
+}
+
+impl<W: Write> Write for CapturingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.captured.extend_from_slice(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: WriteColor> WriteColor for CapturingWriter<W> {
+    fn supports_color(&self) -> bool {
+        self.inner.supports_color()
+    }
+
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        self.inner.set_color(spec)
+    }
+
+    fn reset(&mut self) -> io::Result<()> {
+        self.inner.reset()
+    }
+}
 
 /// Runs tests on the original code and produces a nice informative output.
 ///
@@ -23,12 +83,14 @@ use termcolor::WriteColor;
 ///
 /// # Returns
 ///
-/// * `anyhow::Result<()>` - The result of the test suite for the package.
+/// * `anyhow::Result<Vec<String>>` - The names of all tests executed on the package.
 pub(crate) fn run_tests_on_original_code(
     cfg: &TestBuildConfig,
     package_path: &Path,
-) -> anyhow::Result<()> {
-    let mut error_writer = termcolor::StandardStream::stderr(termcolor::ColorChoice::Auto);
+) -> anyhow::Result<Vec<String>> {
+    let mut error_writer = CapturingWriter::new(termcolor::StandardStream::stderr(
+        termcolor::ColorChoice::Auto,
+    ));
 
     // Show informative statistics to users.
     let report_statistics = true;
@@ -46,7 +108,11 @@ pub(crate) fn run_tests_on_original_code(
         report_statistics,
         num_threads,
         &mut error_writer,
-    );
+    )
+    .and_then(|result| match result {
+        UnitTestResult::Success => Ok(()),
+        UnitTestResult::Failure => Err(Error::msg("Move unit test error")),
+    });
 
     if let Err(e) = result {
         let msg = format!(
@@ -56,7 +122,8 @@ pub(crate) fn run_tests_on_original_code(
         return Err(anyhow!(msg));
     }
 
-    Ok(())
+    let output = String::from_utf8_lossy(&error_writer.captured);
+    Ok(reported_tests(&output, &[TEST_PASS_MARKER]))
 }
 
 /// Runs tests on the mutated code.
@@ -69,14 +136,62 @@ pub(crate) fn run_tests_on_original_code(
 ///
 /// * `cfg` - A `TestBuildConfig` representing the test configuration.
 /// * `package_path` - A `Path` to the package.
+/// * `timeout` - The time limit for the tests, if any.
 ///
 /// # Returns
 ///
-/// * `anyhow::Result<()>` - The result of the test suite for the package.
+/// * `(MutantStatus, Vec<String>)` - The status of the mutant after running the test suite on it
+///   and the names of the tests which killed the mutant.
 pub(crate) fn run_tests_on_mutated_code(
     cfg: &TestBuildConfig,
     package_path: &Path,
-) -> anyhow::Result<()> {
+    timeout: Option<Duration>,
+) -> (MutantStatus, Vec<String>) {
+    let Some(timeout) = timeout else {
+        return test_mutant(cfg, package_path);
+    };
+
+    // The test runner cannot be stopped from the outside, so the tests run in a separate thread
+    // which is left behind when the time runs out.
+    let thread_cfg = cfg.clone();
+    let thread_package_path = package_path.to_path_buf();
+    let job = move || test_mutant(&thread_cfg, &thread_package_path);
+    match run_with_timeout("mutant-tests", timeout, job) {
+        Ok(TimedJob::Finished(result)) => result,
+        Ok(TimedJob::TimedOut(late_result)) => {
+            info!("Tests on the mutant {package_path:?} didn't finish in {timeout:?}");
+            if late_result.is_none() {
+                warn!("The test thread of the timed out mutant {package_path:?} is left running");
+            } else {
+                warn!(
+                    "Too many test threads are left running, waited for the tests on the timed \
+                     out mutant {package_path:?}"
+                );
+            }
+            // The time limit is exceeded anyway, so the result of the tests is ignored.
+            (MutantStatus::Timeout, vec![])
+        },
+        Ok(TimedJob::Panicked) => {
+            warn!("Running unit tests on the mutant {package_path:?} failed unexpectedly");
+            (MutantStatus::ToolError, vec![])
+        },
+        Err(e) => {
+            warn!("Cannot spawn a thread to run tests on the mutant {package_path:?}: {e}");
+            (MutantStatus::ToolError, vec![])
+        },
+    }
+}
+
+/// The reasons why the unit tests couldn't be run on the mutant.
+enum TestRunError {
+    /// The mutated package doesn't compile.
+    Build(Error),
+    /// The package couldn't be prepared for the build, e.g. due to missing dependencies.
+    Tool(Error),
+}
+
+/// Runs tests on the mutated code in the current thread.
+fn test_mutant(cfg: &TestBuildConfig, package_path: &Path) -> (MutantStatus, Vec<String>) {
     // Ignore statistics on mutants.
     let report_statistics = false;
 
@@ -84,7 +199,8 @@ pub(crate) fn run_tests_on_mutated_code(
     let skip_fetch_deps = true;
 
     // No need to print anything to the screen, due to many threads, it might be messy and slow.
-    let mut error_writer = std::io::sink();
+    // The output is kept only to find out why the mutant was killed.
+    let mut error_writer = NoColor::new(Vec::<u8>::new());
 
     // Do not calculate the coverage on mutants.
     let mut test_config = cfg.clone();
@@ -96,14 +212,82 @@ pub(crate) fn run_tests_on_mutated_code(
     // thread should be more than enough. Using more threads here slows the overall time.
     let num_threads = 1;
 
-    run_tests(
-        &test_config,
-        package_path,
-        skip_fetch_deps,
-        report_statistics,
-        num_threads,
-        &mut error_writer,
-    )
+    // Some mutants might crash the test runner, which shouldn't stop testing the other mutants.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        // The package is resolved before running the tests, so the errors which are not caused
+        // by the mutant are not mistaken for the build failures.
+        build_config(&test_config, skip_fetch_deps)
+            .resolution_graph_for_package(package_path, &mut Vec::new())
+            .map_err(TestRunError::Tool)?;
+
+        run_tests(
+            &test_config,
+            package_path,
+            skip_fetch_deps,
+            report_statistics,
+            num_threads,
+            &mut error_writer,
+        )
+        .map_err(TestRunError::Build)
+    }));
+
+    match result {
+        Ok(Ok(UnitTestResult::Success)) => (MutantStatus::Alive, vec![]),
+        Ok(Ok(UnitTestResult::Failure)) => {
+            let output = String::from_utf8_lossy(error_writer.get_ref());
+            trace!("Mutant killed! Unit test failed with output: {output}");
+
+            (
+                failure_status(&output),
+                reported_tests(&output, &[TEST_FAIL_MARKER, TEST_TIMEOUT_MARKER]),
+            )
+        },
+        // Unit tests are not run at all if the package fails to build.
+        Ok(Err(TestRunError::Build(e))) => {
+            trace!("Mutant killed! Unit tests failed with error: {e}");
+            (MutantStatus::KilledByCompileError, vec![])
+        },
+        // The other failures (e.g. unresolved dependencies or IO errors) are not caused by the mutant.
+        Ok(Err(TestRunError::Tool(e))) => {
+            warn!("Cannot run unit tests on the mutant {package_path:?}: {e}");
+            (MutantStatus::ToolError, vec![])
+        },
+        Err(_) => {
+            warn!("Running unit tests on the mutant {package_path:?} panicked");
+            (MutantStatus::ToolError, vec![])
+        },
+    }
+}
+
+/// Finds out why the tests failed on the mutant from the output of the test runner.
+///
+/// Tests exceeding the gas limit are reported as timed out by the test runner. The mutant is
+/// killed by the tests if any of them failed, even when the other tests ran out of gas.
+fn failure_status(output: &str) -> MutantStatus {
+    if reported_tests(output, &[TEST_FAIL_MARKER]).is_empty()
+        && !reported_tests(output, &[TEST_TIMEOUT_MARKER]).is_empty()
+    {
+        MutantStatus::OutOfGas
+    } else {
+        MutantStatus::KilledByTest
+    }
+}
+
+/// Extracts the names of the tests marked with any of the given markers from the output of the test runner.
+///
+/// Note: when the `fail_fast` option is enabled, only the first failing test is reported, which is
+/// recorded in the report with the `killed_by_complete` flag.
+fn reported_tests(output: &str, markers: &[&str]) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            markers.iter().find_map(|marker| line.strip_prefix(marker))
+        })
+        .map(|name| name.trim().to_owned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
 }
 
 /// The `run_tests` function is responsible for running the tests for the provided package.
@@ -116,17 +300,8 @@ fn run_tests<W: WriteColor + Send>(
     report_statistics: bool,
     num_threads: usize,
     mut error_writer: &mut W,
-) -> anyhow::Result<()> {
-    let config = BuildConfig {
-        dev_mode: cfg.move_options.dev,
-        additional_named_addresses: cfg.move_options.named_addresses(),
-        test_mode: true,
-        full_model_generation: cfg.move_options.skip_checks_on_test_code,
-        install_dir: cfg.move_options.output_dir.clone(),
-        skip_fetch_latest_git_deps,
-        compiler_config: cfg.compiler_config(),
-        ..Default::default()
-    };
+) -> anyhow::Result<UnitTestResult> {
+    let config = build_config(cfg, skip_fetch_latest_git_deps);
 
     let natives = aptos_debug_natives(NativeGasParameters::zeros(), MiscGasParameters::zeros());
     let cost_table = None;
@@ -186,8 +361,85 @@ fn run_tests<W: WriteColor + Send>(
         }
     }
 
-    match result {
-        UnitTestResult::Success => Ok(()),
-        UnitTestResult::Failure => Err(Error::msg("Move unit test error")),
+    Ok(result)
+}
+
+/// Returns the directories of the package and of all its resolved dependencies.
+pub(crate) fn package_dirs(
+    cfg: &TestBuildConfig,
+    package_path: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let resolved_graph =
+        build_config(cfg, true).resolution_graph_for_package(package_path, &mut Vec::new())?;
+
+    Ok(resolved_graph
+        .package_table
+        .values()
+        .map(|package| package.package_path.clone())
+        .collect())
+}
+
+/// Creates the build configuration for running the unit tests.
+fn build_config(cfg: &TestBuildConfig, skip_fetch_latest_git_deps: bool) -> BuildConfig {
+    BuildConfig {
+        dev_mode: cfg.move_options.dev,
+        additional_named_addresses: cfg.move_options.named_addresses(),
+        test_mode: true,
+        full_model_generation: cfg.move_options.skip_checks_on_test_code,
+        install_dir: cfg.move_options.output_dir.clone(),
+        skip_fetch_latest_git_deps,
+        compiler_config: cfg.compiler_config(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tests_are_extracted_from_output() {
+        let output = "\
+Running Move unit tests
+[ PASS    ] 0xc0ffee::Sum::sum_test
+[ FAIL    ] 0xc0ffee::Operators::and_test
+[ TIMEOUT ] 0xc0ffee::Operators::loop_test
+[ FAIL    ] 0xc0ffee::Operators::and_test
+
+Test failures:
+";
+
+        assert_eq!(
+            reported_tests(output, &[TEST_FAIL_MARKER, TEST_TIMEOUT_MARKER]),
+            vec![
+                "0xc0ffee::Operators::and_test".to_owned(),
+                "0xc0ffee::Operators::loop_test".to_owned(),
+            ]
+        );
+        assert_eq!(reported_tests(output, &[TEST_PASS_MARKER]), vec![
+            "0xc0ffee::Sum::sum_test".to_owned()
+        ]);
+    }
+
+    #[test]
+    fn tests_running_out_of_gas_do_not_hide_failures() {
+        let timeout = "\
+[ PASS    ] 0xc0ffee::Sum::sum_test
+[ TIMEOUT ] 0xc0ffee::Operators::loop_test
+";
+        assert_eq!(failure_status(timeout), MutantStatus::OutOfGas);
+
+        let mixed = "\
+[ TIMEOUT ] 0xc0ffee::Operators::loop_test
+[ FAIL    ] 0xc0ffee::Operators::and_test
+";
+        assert_eq!(failure_status(mixed), MutantStatus::KilledByTest);
+
+        // The abort messages are not test markers.
+        let aborted = "\
+[ FAIL    ] 0xc0ffee::Operators::abort_test
+    Test was not expected to error, but it aborted with code OUT_OF_GAS
+";
+        assert_eq!(failure_status(aborted), MutantStatus::KilledByTest);
     }
 }
@@ -3,12 +3,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::{Parser, ValueEnum};
-use std::{path::PathBuf, str::FromStr};
+pub use mutator_common::filter::{FunctionFilter, ModuleFilter};
+use serde::Deserialize;
+use std::path::PathBuf;
 
 pub const DEFAULT_OUTPUT_DIR: &str = "mutants_output";
 
 /// Mutation operator mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum OperatorModeArg {
     Light,
     Medium,
@@ -18,21 +21,43 @@ pub enum OperatorModeArg {
 }
 
 /// Command line options for mutator
-#[derive(Parser, Debug, Clone)]
+#[derive(Parser, Debug, Default, Clone)]
 pub struct CLIOptions {
     /// The paths to the Move sources.
     #[clap(long, value_parser)]
     pub move_sources: Vec<PathBuf>,
 
-    /// Module names to be mutated.
-    #[clap(long, value_parser, default_value = "all")]
-    pub mutate_modules: ModuleFilter,
+    /// Optional configuration file for the mutator (TOML, or JSON with the `.json` extension).
+    ///
+    /// The options given on the command line take precedence over the ones from the file.
+    #[clap(long, value_parser)]
+    pub mutator_conf: Option<PathBuf>,
+
+    /// Module names to be mutated (names or glob patterns, e.g. `pool*`).
+    ///
+    /// All modules are mutated by default. The constants of the mutated modules are mutated
+    /// as well.
+    #[clap(long, value_parser)]
+    pub mutate_modules: Option<ModuleFilter>,
+
+    /// Module names not to be mutated (names or glob patterns).
+    #[clap(long, value_parser)]
+    pub exclude_modules: Option<ModuleFilter>,
+
+    /// Function names to be mutated (names or glob patterns, optionally qualified with the module,
+    /// e.g. `pool::swap_*`).
+    ///
+    /// All functions are mutated by default. The constants and the module and struct
+    /// specifications are matched by their pseudo-function names, e.g. `<const NAME>`.
+    #[clap(long, value_parser)]
+    pub mutate_functions: Option<FunctionFilter>,
 
-    /// Function names to be mutated.
-    #[clap(long, value_parser, default_value = "all")]
-    pub mutate_functions: FunctionFilter,
+    /// Function names not to be mutated (names or glob patterns, optionally qualified with the
+    /// module).
+    #[clap(long, value_parser)]
+    pub exclude_functions: Option<FunctionFilter>,
 
-    /// The path where to put the output files.
+    /// The path where to put the output files [default: mutants_output].
     #[clap(long, value_parser)]
     pub out_mutant_dir: Option<PathBuf>,
 
@@ -44,7 +69,31 @@ pub struct CLIOptions {
     #[clap(long)]
     pub downsampling_ratio_percentage: Option<usize>,
 
+    /// Compile every generated mutant and discard the ones which do not compile.
+    #[clap(long)]
+    pub verify_mutants: bool,
+
+    /// Compile every generated mutant and drop the ones with the same bytecode as the original code.
+    #[clap(long)]
+    pub check_equivalence: bool,
+
+    /// Mutate only the functions which have specifications.
+    ///
+    /// Functions without any specification conditions, e.g. without a `spec` block, are skipped.
+    /// The constants of the mutated modules are still mutated.
+    #[clap(long)]
+    pub only_with_specs: bool,
+
+    /// Mutate the specification conditions instead of the code.
+    ///
+    /// The conditions are dropped, negated or weakened and `old(x)` is replaced with `x`.
+    /// The operator mode doesn't apply to the specifications.
+    #[clap(long, conflicts_with = "apply_coverage")]
+    pub mutate_specs: bool,
+
     /// Use the unit test coverage report to generate mutants for source code with unit test coverage.
+    ///
+    /// The constants aren't covered by the report, so they are mutated regardless of it.
     #[clap(long = "coverage", conflicts_with = "move_sources")]
     pub apply_coverage: bool,
 
@@ -53,14 +102,17 @@ pub struct CLIOptions {
     /// - light: binary_operator_swap, break_continue_replacement, delete_statement
     /// - medium: light + literal_replacement
     /// - medium-only: literal_replacement (only what's added in medium)
-    /// - heavy (default): all 7 operators
+    /// - heavy (default): all 7 ranked operators
     /// - heavy-only: unary_operator_replacement, binary_operator_replacement, if_else_replacement (only what's added in heavy)
     #[clap(long, value_enum, conflicts_with = "operators")]
     pub mode: Option<OperatorModeArg>,
 
     /// Custom operator selection to run mutations on (comma-separated).
     ///
-    /// Available operators: unary_operator_replacement, delete_statement, break_continue_replacement, binary_operator_replacement, if_else_replacement, literal_replacement, binary_operator_swap
+    /// Available operators: unary_operator_replacement, delete_statement, break_continue_replacement, binary_operator_replacement, if_else_replacement, literal_replacement, binary_operator_swap, return_value_replacement, assert_replacement, abort_code_replacement
+    ///
+    /// The return_value_replacement, assert_replacement and abort_code_replacement operators are
+    /// not included in any mode, they are applied only when listed here.
     #[clap(long, value_parser, value_delimiter = ',', conflicts_with = "mode")]
     pub operators: Option<Vec<String>>,
 }
@@ -89,64 +141,3 @@ impl<'a> PackagePathCheck<'a> for CLIOptions {
         &self.move_sources
     }
 }
-
-impl Default for CLIOptions {
-    // We need to implement default just because we need to specify the default value for out_mutant_dir.
-    // Otherwise, out_mutant_dir would be empty. This is special case, when user won't specify any Options
-    // (so the default value would be used), but define package_path (which is passed using other mechanism).
-    fn default() -> Self {
-        Self {
-            move_sources: vec![],
-            mutate_modules: ModuleFilter::All,
-            mutate_functions: FunctionFilter::All,
-            out_mutant_dir: Some(PathBuf::from(DEFAULT_OUTPUT_DIR)),
-            no_overwrite: false,
-            apply_coverage: false,
-            downsampling_ratio_percentage: None,
-            mode: None,
-            operators: None,
-        }
-    }
-}
-
-/// Filter allowing to select modules to be mutated.
-#[derive(Default, Debug, Clone, PartialEq)]
-pub enum ModuleFilter {
-    #[default]
-    All,
-    Selected(Vec<String>),
-}
-
-impl FromStr for ModuleFilter {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "all" => Ok(ModuleFilter::All),
-            _ => Ok(ModuleFilter::Selected(
-                s.split(&[';', '-', ',']).map(String::from).collect(),
-            )),
-        }
-    }
-}
-
-/// Filter for the functions to mutate.
-#[derive(Default, Debug, Clone, PartialEq)]
-pub enum FunctionFilter {
-    #[default]
-    All,
-    Selected(Vec<String>),
-}
-
-impl FromStr for FunctionFilter {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "all" => Ok(FunctionFilter::All),
-            _ => Ok(FunctionFilter::Selected(
-                s.split(&[';', '-', ',']).map(String::from).collect(),
-            )),
-        }
-    }
-}
@@ -6,12 +6,16 @@ use crate::configuration::Configuration;
 use codespan_reporting::diagnostic::Severity;
 use either::Either;
 use itertools::Itertools;
-use legacy_move_compiler::shared::{
-    known_attributes::{AttributeKind, KnownAttribute},
-    Flags,
+use legacy_move_compiler::{
+    compiled_unit::{CompiledUnit, NamedCompiledModule},
+    shared::{
+        known_attributes::{AttributeKind, KnownAttribute},
+        Flags,
+    },
 };
+use move_binary_format::CompiledModule;
 use move_command_line_common::{address::NumericalAddress, parser::NumberFormat};
-use move_compiler_v2::run_checker;
+use move_compiler_v2::{run_checker, run_move_compiler};
 use move_model::model::GlobalEnv;
 use move_package::{
     compilation::compiled_package::{make_source_and_deps_for_compiler, CompiledPackage},
@@ -20,6 +24,7 @@ use move_package::{
 };
 use move_symbol_pool::Symbol;
 use std::{collections::BTreeMap, path::Path};
+use termcolor::NoColor;
 
 /// Generate the AST from the Move sources.
 ///
@@ -56,6 +61,46 @@ pub fn generate_ast(
 ) -> Result<GlobalEnv, anyhow::Error> {
     trace!("Generating AST for package: {package_path:?} and config: {config:?}");
 
+    let options = prepare_compiler_options(mutator_config, config, package_path)?;
+
+    trace!("{options:?}");
+    let env = run_checker(options)?;
+
+    if env.has_errors() {
+        let mut error_writer = termcolor::StandardStream::stderr(termcolor::ColorChoice::Auto);
+        env.report_diag(&mut error_writer, Severity::Warning);
+        anyhow::bail!("AST generation failed");
+    }
+
+    trace!("Sources parsed successfully, AST generated");
+
+    Ok(env)
+}
+
+/// Prepare the compiler options for the package or the Move sources present in the mutator configuration.
+///
+/// # Arguments
+///
+/// * `mutator_config` - configuration of the mutator tool.
+/// * `config` - contains the actual build configuration.
+/// * `package_path` - the path to the Move package.
+///
+/// # Errors
+///
+/// * If any error occurs during the preparation, the appropriate error is returned using anyhow.
+///
+/// # Panics
+///
+/// This function panics if the source path contains invalid characters.
+///
+/// # Returns
+///
+/// * `Result<move_compiler_v2::Options, anyhow::Error>` - the compiler options if successful, or an error if any error occurs.
+pub(crate) fn prepare_compiler_options(
+    mutator_config: &Configuration,
+    config: &BuildConfig,
+    package_path: &Path,
+) -> Result<move_compiler_v2::Options, anyhow::Error> {
     let source_files = mutator_config
         .project
         .move_sources
@@ -68,24 +113,126 @@ pub fn generate_ast(
     // If the `-m` option is specified, we should use only `move_sources`. Using Move source means we won't
     // check for deps or resolve names as there might be no standard package layout. That means we can mutate
     // only quite simple files.
-    let options = if is_package {
-        prepare_compiler_for_package(config, package_path)?
+    if is_package {
+        prepare_compiler_for_package(config, package_path)
     } else {
-        prepare_compiler_for_files(config, source_files.as_slice())
-    };
+        Ok(prepare_compiler_for_files(config, source_files.as_slice()))
+    }
+}
 
-    trace!("{options:?}");
-    let env = run_checker(options)?;
+/// Checks if the mutant compiles.
+///
+/// The check is performed by running the compiler checker on the same set of sources which was used
+/// to generate the AST, with the original file replaced by the mutant file.
+///
+/// # Arguments
+///
+/// * `options` - the compiler options used to generate the AST.
+/// * `original_file` - the canonicalized path to the original file.
+/// * `mutant_file` - the path to the mutant file.
+///
+/// # Errors
+///
+/// * If the original file cannot be found among the compiled sources, the mutant cannot be verified and
+///   an error is returned.
+///
+/// # Returns
+///
+/// * `anyhow::Result<bool>` - `true` if the mutant compiles, `false` otherwise.
+pub(crate) fn verify_mutant(
+    options: &move_compiler_v2::Options,
+    original_file: &Path,
+    mutant_file: &Path,
+) -> anyhow::Result<bool> {
+    let options = replace_source(options, original_file, mutant_file)?;
+
+    // Errors returned from the checker are compilation errors as well.
+    Ok(run_checker(options).is_ok_and(|env| !env.has_errors()))
+}
+
+/// Compiles the mutant to the bytecode.
+///
+/// The mutant is compiled on the same set of sources which was used to generate the AST, with the
+/// original file replaced by the mutant file. The mutant is trivially equivalent to the original
+/// code if the bytecode of all compiled modules is identical to the bytecode of the original modules.
+///
+/// # Arguments
+///
+/// * `options` - the compiler options used to generate the AST.
+/// * `original_file` - the canonicalized path to the original file.
+/// * `mutant_file` - the path to the mutant file.
+///
+/// # Errors
+///
+/// * If the original file cannot be found among the compiled sources, an error is returned.
+///
+/// # Returns
+///
+/// * `anyhow::Result<Option<Vec<CompiledModule>>>` - the compiled modules, or `None` if the mutant
+///   does not compile.
+pub(crate) fn compile_mutant(
+    options: &move_compiler_v2::Options,
+    original_file: &Path,
+    mutant_file: &Path,
+) -> anyhow::Result<Option<Vec<CompiledModule>>> {
+    let options = replace_source(options, original_file, mutant_file)?;
+
+    Ok(compile_modules(&options).ok())
+}
+
+/// Compiles the sources to the bytecode and returns the compiled modules.
+///
+/// # Errors
+///
+/// * If the sources don't compile, an error is returned.
+pub(crate) fn compile_modules(
+    options: &move_compiler_v2::Options,
+) -> anyhow::Result<Vec<CompiledModule>> {
+    // Diagnostics are not interesting here, the mutants are expected to fail sometimes.
+    let mut error_writer = NoColor::new(Vec::<u8>::new());
+    let mut emitter = options.error_emitter(&mut error_writer);
+    let (env, units) = run_move_compiler(emitter.as_mut(), options.clone())?;
 
     if env.has_errors() {
-        let mut error_writer = termcolor::StandardStream::stderr(termcolor::ColorChoice::Auto);
-        env.report_diag(&mut error_writer, Severity::Warning);
-        anyhow::bail!("AST generation failed");
+        anyhow::bail!("compilation failed");
     }
 
-    trace!("Sources parsed successfully, AST generated");
+    Ok(units
+        .into_iter()
+        .filter_map(|unit| match unit.into_compiled_unit() {
+            CompiledUnit::Module(NamedCompiledModule { module, .. }) => Some(module),
+            CompiledUnit::Script(_) => None,
+        })
+        .collect())
+}
 
-    Ok(env)
+/// Returns the compiler options with the original file replaced by the mutant file among the sources.
+fn replace_source(
+    options: &move_compiler_v2::Options,
+    original_file: &Path,
+    mutant_file: &Path,
+) -> anyhow::Result<move_compiler_v2::Options> {
+    let mutant_file = mutant_file.to_str().ok_or_else(|| {
+        anyhow::anyhow!("mutant path {mutant_file:?} contains invalid characters")
+    })?;
+
+    let mut options = options.clone();
+    let mut replaced = false;
+    for source in &mut options.sources {
+        if Path::new(source)
+            .canonicalize()
+            .is_ok_and(|path| path == original_file)
+        {
+            *source = mutant_file.to_owned();
+            replaced = true;
+        }
+    }
+
+    if !replaced {
+        anyhow::bail!("{original_file:?} is not among the compiled sources");
+    }
+
+    Ok(options)
 }
 
 /// Prepare the compiler for the given package.
@@ -3,11 +3,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    cli::{CLIOptions, OperatorModeArg},
+    cli::{CLIOptions, FunctionFilter, ModuleFilter, OperatorModeArg},
     coverage::Coverage,
     operator_filter::OperatorMode,
 };
-use std::path::PathBuf;
+use anyhow::Context;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 /// Mutator configuration for the Move project.
 #[derive(Debug, Default)]
@@ -24,7 +29,12 @@ pub struct Configuration {
 
 impl Configuration {
     /// Creates a new configuration using command line options.
-    pub fn new(project: CLIOptions, project_path: Option<PathBuf>) -> anyhow::Result<Self> {
+    pub fn new(mut project: CLIOptions, project_path: Option<PathBuf>) -> anyhow::Result<Self> {
+        if let Some(path) = &project.mutator_conf {
+            let file = MutatorConfigFile::load(path)?;
+            file.apply_to(&mut project)?;
+        }
+
         // Parse and validate the operator mode from CLI options
         let operator_mode = Self::parse_operator_mode(&project)?;
 
@@ -64,3 +74,244 @@ impl Configuration {
         }
     }
 }
+
+/// Mutator configuration file, which can be checked in instead of passing long command lines.
+///
+/// The file is written in TOML, or in JSON when it has the `.json` extension. All fields are
+/// optional and use the same names and values as the command line options, e.g.:
+///
+/// ```toml
+/// mutate_modules = ["pool*", "router"]
+/// exclude_modules = ["*_bindings"]
+/// mutate_functions = ["pool::swap_*", "add_liquidity"]
+/// mode = "medium"
+/// downsampling_ratio_percentage = 50
+/// out_mutant_dir = "mutants_output"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MutatorConfigFile {
+    /// Module names to be mutated.
+    pub mutate_modules: Option<Vec<String>>,
+    /// Module names not to be mutated.
+    pub exclude_modules: Option<Vec<String>>,
+    /// Function names to be mutated.
+    pub mutate_functions: Option<Vec<String>>,
+    /// Function names not to be mutated.
+    pub exclude_functions: Option<Vec<String>>,
+    /// Mutation operator mode.
+    pub mode: Option<OperatorModeArg>,
+    /// Custom operator selection.
+    pub operators: Option<Vec<String>>,
+    /// Percentage of mutants to remove.
+    pub downsampling_ratio_percentage: Option<usize>,
+    /// The path where to put the output files.
+    pub out_mutant_dir: Option<PathBuf>,
+}
+
+impl MutatorConfigFile {
+    /// Loads the configuration file, choosing the format by the file extension.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("cannot read mutator configuration {}", path.display()))?;
+
+        let parsed: anyhow::Result<Self> = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content).map_err(anyhow::Error::from)
+        } else {
+            toml::from_str(&content).map_err(anyhow::Error::from)
+        };
+        let file =
+            parsed.with_context(|| format!("invalid mutator configuration {}", path.display()))?;
+
+        if file.mode.is_some() && file.operators.is_some() {
+            anyhow::bail!(
+                "invalid mutator configuration {}: `mode` and `operators` cannot be used together",
+                path.display()
+            );
+        }
+
+        Ok(file)
+    }
+
+    /// Checks that the file doesn't set `out_mutant_dir`, for the tools which keep the mutants in
+    /// their own output directories.
+    pub fn reject_out_mutant_dir(path: &Path) -> anyhow::Result<()> {
+        if Self::load(path)?.out_mutant_dir.is_some() {
+            anyhow::bail!(
+                "invalid mutator configuration {}: `out_mutant_dir` can be used only with the mutator tool",
+                path.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fills the options which weren't given on the command line with the values from the file.
+    pub fn apply_to(self, options: &mut CLIOptions) -> anyhow::Result<()> {
+        if options.mutate_modules.is_none() {
+            options.mutate_modules = self.mutate_modules.map(ModuleFilter::Selected);
+        }
+
+        if options.exclude_modules.is_none() {
+            options.exclude_modules = self.exclude_modules.map(ModuleFilter::Selected);
+        }
+
+        if options.mutate_functions.is_none() {
+            options.mutate_functions = self.mutate_functions.map(FunctionFilter::Selected);
+        }
+
+        if options.exclude_functions.is_none() {
+            options.exclude_functions = self.exclude_functions.map(FunctionFilter::Selected);
+        }
+
+        // The mode and the operators exclude each other, so either of them given on the command
+        // line overrides both values from the file.
+        if options.mode.is_none() && options.operators.is_none() {
+            if let Some(operators) = &self.operators {
+                OperatorMode::validate_operators(operators)?;
+            }
+            options.mode = self.mode;
+            options.operators = self.operators;
+        }
+
+        if options.downsampling_ratio_percentage.is_none() {
+            options.downsampling_ratio_percentage = self.downsampling_ratio_percentage;
+        }
+
+        if options.out_mutant_dir.is_none() {
+            options.out_mutant_dir = self.out_mutant_dir;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::tempdir;
+
+    #[test]
+    fn config_file_fills_missing_options() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mutator.toml");
+        fs::write(
+            &path,
+            r#"
+                mutate_modules = ["pool"]
+                mutate_functions = ["swap", "add_liquidity"]
+                mode = "medium-only"
+                downsampling_ratio_percentage = 50
+                out_mutant_dir = "out"
+            "#,
+        )
+        .unwrap();
+
+        let mut options = CLIOptions {
+            mutate_functions: Some(FunctionFilter::Selected(
+                vec!["remove_liquidity".to_owned()],
+            )),
+            operators: Some(vec!["delete_statement".to_owned()]),
+            ..Default::default()
+        };
+        MutatorConfigFile::load(&path)
+            .unwrap()
+            .apply_to(&mut options)
+            .unwrap();
+
+        assert_eq!(
+            options.mutate_modules,
+            Some(ModuleFilter::Selected(vec!["pool".to_owned()]))
+        );
+        // The command line options win.
+        assert_eq!(
+            options.mutate_functions,
+            Some(FunctionFilter::Selected(
+                vec!["remove_liquidity".to_owned()]
+            ))
+        );
+        assert_eq!(options.mode, None);
+        assert_eq!(options.operators, Some(vec!["delete_statement".to_owned()]));
+        assert_eq!(options.downsampling_ratio_percentage, Some(50));
+        assert_eq!(options.out_mutant_dir, Some(PathBuf::from("out")));
+    }
+
+    #[test]
+    fn explicit_default_options_override_config_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mutator.toml");
+        fs::write(
+            &path,
+            r#"
+                mutate_modules = ["pool"]
+                mutate_functions = ["swap"]
+                mode = "light"
+            "#,
+        )
+        .unwrap();
+
+        let mut options = CLIOptions::parse_from([
+            "move-mutator",
+            "--mutate-modules",
+            "all",
+            "--mutate-functions",
+            "all",
+            "--mode",
+            "heavy",
+        ]);
+        MutatorConfigFile::load(&path)
+            .unwrap()
+            .apply_to(&mut options)
+            .unwrap();
+
+        assert_eq!(options.mutate_modules, Some(ModuleFilter::All));
+        assert_eq!(options.mutate_functions, Some(FunctionFilter::All));
+        assert_eq!(options.mode, Some(OperatorModeArg::Heavy));
+    }
+
+    #[test]
+    fn config_file_can_be_json() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mutator.json");
+        fs::write(&path, r#"{ "operators": ["delete_statement"] }"#).unwrap();
+
+        let file = MutatorConfigFile::load(&path).unwrap();
+        assert_eq!(file.operators, Some(vec!["delete_statement".to_owned()]));
+    }
+
+    #[test]
+    fn invalid_config_file_is_rejected() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mutator.toml");
+
+        fs::write(&path, "mutate_module = [\"pool\"]").unwrap();
+        assert!(MutatorConfigFile::load(&path).is_err());
+
+        fs::write(
+            &path,
+            "mode = \"light\"\noperators = [\"delete_statement\"]",
+        )
+        .unwrap();
+        assert!(MutatorConfigFile::load(&path).is_err());
+
+        fs::write(&path, "operators = [\"unknown\"]").unwrap();
+        let mut options = CLIOptions::default();
+        assert!(MutatorConfigFile::load(&path)
+            .unwrap()
+            .apply_to(&mut options)
+            .is_err());
+    }
+
+    #[test]
+    fn out_mutant_dir_is_rejected_when_requested() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mutator.toml");
+
+        fs::write(&path, "mode = \"light\"").unwrap();
+        assert!(MutatorConfigFile::reject_out_mutant_dir(&path).is_ok());
+
+        fs::write(&path, "out_mutant_dir = \"out\"").unwrap();
+        assert!(MutatorConfigFile::reject_out_mutant_dir(&path).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Comment directives which suppress the mutations in the selected parts of the source code.
+//!
+//! The directives are written as line comments:
+//! - `// mutation:skip-next-line` suppresses the mutations on the next line,
+//! - `// mutation:skip-start` and `// mutation:skip-end` suppress the mutations between them,
+//! - `// mutation:skip` suppresses the mutations on the line it follows, or on the next line when
+//!   it's placed on a separate line.
+//!
+//! Each directive can be limited to the given operators, e.g. `// mutation:skip(literal_replacement)`.
+//! A mutation is suppressed when it starts inside the suppressed part of the source.
+
+use crate::{operator_filter::OperatorMode, report::Mutation};
+use std::ops::Range;
+
+/// The prefix of all comment directives.
+const DIRECTIVE_PREFIX: &str = "mutation:skip";
+
+/// The kind of the directive, determined by the suffix after the prefix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DirectiveKind {
+    /// `mutation:skip` - the current line, or the next one for a comment on a separate line.
+    Line,
+    /// `mutation:skip-next-line`
+    NextLine,
+    /// `mutation:skip-start`
+    Start,
+    /// `mutation:skip-end`
+    End,
+}
+
+/// A part of the source in which the mutations are suppressed.
+#[derive(Debug, Clone, PartialEq)]
+struct SkippedRegion {
+    /// Byte offsets of the region in the source.
+    span: Range<usize>,
+    /// The operators to suppress, or `None` for all of them.
+    operators: Option<Vec<String>>,
+}
+
+/// The mutation directives found in a single source file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Directives {
+    regions: Vec<SkippedRegion>,
+}
+
+impl Directives {
+    /// Parses the directives from the original source of the file.
+    ///
+    /// Malformed directives, unknown operator names and unmatched `skip-start`/`skip-end` pairs are
+    /// reported as warnings.
+    /// A `skip-start` without a matching `skip-end` suppresses the mutations till the end of the file.
+    pub(crate) fn parse(source: &str) -> Self {
+        // Byte ranges of all lines, including the line terminators.
+        let mut lines = vec![];
+        let mut offset = 0;
+        for line in source.split_inclusive('\n') {
+            lines.push(offset..offset + line.len());
+            offset += line.len();
+        }
+
+        let mut regions = vec![];
+        let mut open_region: Option<(usize, Option<Vec<String>>)> = None;
+
+        for (idx, line_span) in lines.iter().enumerate() {
+            let line = &source[line_span.clone()];
+            let Some((comment_pos, directive)) = find_directive(line) else {
+                continue;
+            };
+
+            let Some((kind, operators)) = parse_directive(directive) else {
+                warn!(
+                    "ignoring malformed mutation directive on line {}: {}",
+                    idx + 1,
+                    directive.trim_end()
+                );
+                continue;
+            };
+
+            // Unknown operators are kept, they just never match any mutation.
+            if let Some(Err(e)) = operators.as_deref().map(OperatorMode::parse_operators) {
+                warn!("mutation directive on line {}: {e}", idx + 1);
+            }
+
+            let next_line = lines
+                .get(idx + 1)
+                .cloned()
+                .unwrap_or(source.len()..source.len());
+            match kind {
+                DirectiveKind::Line if !line[..comment_pos].trim().is_empty() => {
+                    regions.push(SkippedRegion {
+                        span: line_span.clone(),
+                        operators,
+                    });
+                },
+                DirectiveKind::Line | DirectiveKind::NextLine => {
+                    regions.push(SkippedRegion {
+                        span: next_line,
+                        operators,
+                    });
+                },
+                DirectiveKind::Start => {
+                    if open_region.is_some() {
+                        warn!("nested mutation:skip-start on line {} is ignored", idx + 1);
+                        continue;
+                    }
+                    open_region = Some((line_span.end, operators));
+                },
+                DirectiveKind::End => {
+                    let Some((start, operators)) = open_region.take() else {
+                        warn!(
+                            "mutation:skip-end on line {} has no matching mutation:skip-start",
+                            idx + 1
+                        );
+                        continue;
+                    };
+                    regions.push(SkippedRegion {
+                        span: start..line_span.start,
+                        operators,
+                    });
+                },
+            }
+        }
+
+        if let Some((start, operators)) = open_region {
+            warn!("mutation:skip-start without a matching mutation:skip-end, skipping the rest of the file");
+            regions.push(SkippedRegion {
+                span: start..source.len(),
+                operators,
+            });
+        }
+
+        Self { regions }
+    }
+
+    /// Checks whether the mutation is suppressed by any of the directives.
+    pub(crate) fn suppresses(&self, mutation: &Mutation) -> bool {
+        let start = mutation.get_changed_place().start();
+        self.regions.iter().any(|region| {
+            region.span.contains(&start)
+                && region.operators.as_ref().is_none_or(|operators| {
+                    operators
+                        .iter()
+                        .any(|op| op == mutation.get_operator_name())
+                })
+        })
+    }
+}
+
+/// Finds the directive in the line comment, if any.
+///
+/// Returns the position of the comment in the line and the directive text.
+fn find_directive(line: &str) -> Option<(usize, &str)> {
+    line.match_indices("//").find_map(|(pos, _)| {
+        let comment = line[pos + 2..].trim_start();
+        comment
+            .starts_with(DIRECTIVE_PREFIX)
+            .then_some((pos, comment))
+    })
+}
+
+/// Parses the directive kind and the list of operators it applies to.
+fn parse_directive(directive: &str) -> Option<(DirectiveKind, Option<Vec<String>>)> {
+    // Anything after the directive is a free-form comment, e.g. the reason for the suppression.
+    let directive = directive.strip_prefix(DIRECTIVE_PREFIX)?;
+    let (kind, rest) = [
+        ("-next-line", DirectiveKind::NextLine),
+        ("-start", DirectiveKind::Start),
+        ("-end", DirectiveKind::End),
+    ]
+    .into_iter()
+    .find_map(|(suffix, kind)| directive.strip_prefix(suffix).map(|rest| (kind, rest)))
+    .unwrap_or((DirectiveKind::Line, directive));
+
+    let Some(list) = rest.strip_prefix('(') else {
+        return (rest.is_empty() || rest.starts_with(char::is_whitespace)).then_some((kind, None));
+    };
+
+    let (list, _) = list.split_once(')')?;
+    let operators = list
+        .split(',')
+        .map(|op| op.trim().to_owned())
+        .filter(|op| !op.is_empty())
+        .collect::<Vec<_>>();
+
+    // An empty list would silently suppress nothing.
+    (!operators.is_empty() && kind != DirectiveKind::End).then_some((kind, Some(operators)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Range;
+
+    fn mutation(source: &str, text: &str, operator: &str) -> Mutation {
+        let start = source.find(text).unwrap();
+        Mutation::new(
+            Range::new(start, start + text.len()),
+            operator.to_owned(),
+            text.to_owned(),
+            String::new(),
+        )
+    }
+
+    #[test]
+    fn next_line_and_trailing_directives_suppress_single_lines() {
+        let source = "\
+let a = 1;
+// mutation:skip-next-line
+let b = 2;
+let c = 3; // mutation:skip(literal_replacement)
+// mutation:skip
+let d = 4;
+let e = 5;
+";
+        let directives = Directives::parse(source);
+        let op = "literal_replacement";
+
+        assert!(!directives.suppresses(&mutation(source, "1", op)));
+        assert!(directives.suppresses(&mutation(source, "2", op)));
+        assert!(directives.suppresses(&mutation(source, "3", op)));
+        assert!(!directives.suppresses(&mutation(source, "let c = 3;", "delete_statement")));
+        assert!(directives.suppresses(&mutation(source, "4", op)));
+        assert!(!directives.suppresses(&mutation(source, "5", op)));
+    }
+
+    #[test]
+    fn regions_suppress_all_lines_between_start_and_end() {
+        let source = "\
+let a = 1;
+// mutation:skip-start(binary_operator_replacement, delete_statement)
+let b = a + 2;
+let c = b + 3;
+// mutation:skip-end
+let d = c + 4;
+";
+        let directives = Directives::parse(source);
+        let op = "binary_operator_replacement";
+
+        assert!(directives.suppresses(&mutation(source, "+ 2", op)));
+        assert!(directives.suppresses(&mutation(source, "let c = b + 3;", "delete_statement")));
+        assert!(!directives.suppresses(&mutation(source, "3", "literal_replacement")));
+        assert!(!directives.suppresses(&mutation(source, "+ 4", op)));
+    }
+
+    #[test]
+    fn malformed_and_unmatched_directives() {
+        let source = "\
+// mutation:skip-end
+let a = 1; // mutation:skipped
+let b = 2; // mutation:skip()
+// mutation:skip-start
+let c = 3;
+";
+        let directives = Directives::parse(source);
+        let op = "literal_replacement";
+
+        assert!(!directives.suppresses(&mutation(source, "1", op)));
+        assert!(!directives.suppresses(&mutation(source, "2", op)));
+        // Unclosed region lasts till the end of the file.
+        assert!(directives.suppresses(&mutation(source, "3", op)));
+    }
+}
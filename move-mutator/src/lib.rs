@@ -13,6 +13,7 @@ mod mutate;
 
 pub mod configuration;
 pub(crate) mod coverage;
+mod directives;
 mod mutant;
 mod operator;
 pub mod operator_filter;
@@ -23,17 +24,38 @@ pub mod report;
 use crate::{
     compiler::generate_ast,
     configuration::Configuration,
-    report::{MutationReport, Report},
+    directives::Directives,
+    operator::MutantInfo,
+    report::{Mutation, MutationReport, Report},
 };
+use codespan::FileId;
 use move_package::BuildConfig;
 use mutator_common::tmp_package_dir::setup_outdir_and_package_path;
 use rand::{seq::SliceRandom, thread_rng};
 use rayon::prelude::*;
 use std::{
+    collections::{hash_map::Entry, HashMap},
     fs,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
+/// A mutant applied to the source, with all the details needed to write and report it.
+#[derive(Clone)]
+struct TransformedMutant<'a> {
+    /// The mutated source and the mutation which produced it.
+    info: MutantInfo,
+    /// The stable identity of the mutant.
+    stable_id: String,
+    function: String,
+    module: String,
+    /// The canonical path of the mutated file.
+    path: PathBuf,
+    original_source: &'a str,
+    /// The mutations of other operators which produced the same mutant.
+    duplicates: Vec<Mutation>,
+}
+
 /// Runs the Move mutator tool.
 /// Entry point for the Move mutator tool both for the CLI and the Rust API.
 ///
@@ -79,7 +101,11 @@ pub fn run_move_mutator(
 
     trace!("Mutator configuration: {mutator_configuration:?}");
 
-    let enabled_operators = mutator_configuration.operator_mode.get_operators();
+    let enabled_operators = if mutator_configuration.project.mutate_specs {
+        operators::SPEC_OPERATORS.to_vec()
+    } else {
+        mutator_configuration.operator_mode.get_operators()
+    };
     println!(
         "Operator types being mutated ({}):",
         enabled_operators.len()
@@ -108,11 +134,38 @@ pub fn run_move_mutator(
             .compute_coverage(&config, &package_path)?;
     }
 
+    // Verification and equivalence check of mutants need the same set of sources which was used
+    // for the AST generation.
+    let compiler_options = if mutator_configuration.project.verify_mutants
+        || mutator_configuration.project.check_equivalence
+    {
+        Some(compiler::prepare_compiler_options(
+            &mutator_configuration,
+            &config,
+            &package_path,
+        )?)
+    } else {
+        None
+    };
+
+    // Trivial equivalence of mutants is checked by comparing their bytecode with the original one.
+    let original_modules = match &compiler_options {
+        Some(options) if mutator_configuration.project.check_equivalence => {
+            Some(compiler::compile_modules(options)?)
+        },
+        _ => None,
+    };
+    let verify_mutants = mutator_configuration.project.verify_mutants;
+
     let mutants = mutate::mutate(&env, &mutator_configuration)?;
     let output_dir = output::setup_output_dir(&mutator_configuration)?;
 
+    // The comment directives of each file, parsed when the first mutant in the file is applied.
+    let mut file_directives: HashMap<FileId, Directives> = HashMap::new();
+    let mut suppressed_mutants = 0;
+
     // Generate mutants and extract all info needed for rayon threads below.
-    let mut transformed_mutants: Vec<_> = mutants
+    let transformed_mutants: Vec<_> = mutants
         .into_iter()
         .flat_map(|mutant| {
             let file_id = mutant.get_file_id();
@@ -123,23 +176,70 @@ pub fn run_move_mutator(
                 .expect("canonicalizing failed");
             let fn_name = mutant.get_function_name().unwrap_or_default();
             let mod_name = mutant.get_module_name().unwrap_or("script".to_owned());
+            let fn_start = mutant.get_function_start();
+            let directives = file_directives
+                .entry(file_id)
+                .or_insert_with(|| Directives::parse(original_source));
 
             mutant
                 .apply(original_source)
                 .into_iter()
-                .map(|mutant_info| {
-                    (
-                        mutant_info,
-                        fn_name.clone(),
-                        mod_name.clone(),
-                        path.clone(),
-                        original_source,
-                    )
+                .filter(|mutant_info| {
+                    let suppressed = directives.suppresses(&mutant_info.mutation);
+                    if suppressed {
+                        trace!(
+                            "Mutant {:?} is suppressed by a directive",
+                            mutant_info.mutation
+                        );
+                        suppressed_mutants += 1;
+                    }
+                    !suppressed
+                })
+                .map(|mutant_info| TransformedMutant {
+                    stable_id: mutant_info.stable_id(&mod_name, &fn_name, fn_start),
+                    info: mutant_info,
+                    function: fn_name.clone(),
+                    module: mod_name.clone(),
+                    path: path.clone(),
+                    original_source,
+                    duplicates: vec![],
                 })
                 .collect::<Vec<_>>()
         })
         .collect();
 
+    if suppressed_mutants > 0 {
+        println!("Suppressed {suppressed_mutants} mutants by the comment directives");
+    }
+
+    // Different operators can produce exactly the same mutant (e.g. replacing the same condition
+    // with `true`). Keep only the first one and record the mutations of the removed ones.
+    let total_mutants = transformed_mutants.len();
+    // Indices of the kept mutants by their files and mutated sources.
+    let mut unique_mutants: HashMap<(PathBuf, String), usize> = HashMap::new();
+    let mut deduplicated_mutants: Vec<TransformedMutant> = Vec::with_capacity(total_mutants);
+    for mutant in transformed_mutants {
+        let key = (mutant.path.clone(), mutant.info.mutated_source.clone());
+        match unique_mutants.entry(key) {
+            Entry::Occupied(kept) => {
+                trace!("Mutant {:?} is a duplicate", mutant.info.mutation);
+                deduplicated_mutants[*kept.get()]
+                    .duplicates
+                    .push(mutant.info.mutation);
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(deduplicated_mutants.len());
+                deduplicated_mutants.push(mutant);
+            },
+        }
+    }
+
+    let duplicate_mutants = total_mutants - deduplicated_mutants.len();
+    if duplicate_mutants > 0 {
+        println!("Removed {duplicate_mutants} duplicate mutants");
+    }
+    let mut transformed_mutants = deduplicated_mutants;
+
     // If the downsample ratio is set, we need to downsample the mutants.
     if let Some(percentage) = mutator_configuration.project.downsampling_ratio_percentage {
         let total_mutants = transformed_mutants.len();
@@ -159,49 +259,118 @@ pub fn run_move_mutator(
             .collect();
     }
 
-    let mutation_reports: Vec<MutationReport> = transformed_mutants
+    let discarded_mutants = AtomicUsize::new(0);
+    let mutation_reports: Vec<(MutationReport, bool)> = transformed_mutants
         .into_par_iter()
-        .map(|(mutated_info, function, module, path, original_source)| {
-            // An informative description for the mutant.
-            let mutant = format!("{module}::{function}: {:?}", mutated_info.mutation);
-
-            // In case the number of mutants is very low, a single thread might be used.
-            let rayon_tid = rayon::current_thread_index().unwrap_or(0);
-            info!("job_{rayon_tid}: Checking mutant {mutant}");
-
-            let mutant_id = mutated_info.unique_id();
-            let Ok(mutant_path) = output::setup_mutant_path(&output_dir, &path, mutant_id) else {
-                // If we cannot set up the mutant path, we skip the mutant.
-                trace!("Cannot set up mutant path for {path:?}");
-                return None;
-            };
-
-            // Should never fail.
-            fs::write(&mutant_path, &mutated_info.mutated_source)
-                .expect("failed to write mutant to a file");
-
-            info!(
-                "job_{rayon_tid}: {mutant} written to {}",
-                mutant_path.display()
-            );
-            let mut entry = report::MutationReport::new(
-                mutant_path.as_path(),
-                &path,
-                &module,
-                &function,
-                &mutated_info.mutated_source,
-                original_source,
-            );
-
-            entry.add_modification(mutated_info.mutation);
-            Some(entry)
-        })
+        .map(
+            |TransformedMutant {
+                 info: mutated_info,
+                 stable_id,
+                 function,
+                 module,
+                 path,
+                 original_source,
+                 duplicates,
+             }| {
+                // An informative description for the mutant.
+                let mutant = format!("{module}::{function}: {:?}", mutated_info.mutation);
+
+                // In case the number of mutants is very low, a single thread might be used.
+                let rayon_tid = rayon::current_thread_index().unwrap_or(0);
+                info!("job_{rayon_tid}: Checking mutant {mutant}");
+
+                let mutant_id = mutated_info.unique_id();
+                let Ok(mutant_path) = output::setup_mutant_path(&output_dir, &path, mutant_id)
+                else {
+                    // If we cannot set up the mutant path, we skip the mutant.
+                    trace!("Cannot set up mutant path for {path:?}");
+                    return None;
+                };
+
+                // Should never fail.
+                fs::write(&mutant_path, &mutated_info.mutated_source)
+                    .expect("failed to write mutant to a file");
+
+                info!(
+                    "job_{rayon_tid}: {mutant} written to {}",
+                    mutant_path.display()
+                );
+
+                // A single compilation of the mutant both verifies it and checks its equivalence.
+                let compilation = match (&compiler_options, &original_modules) {
+                    (Some(options), Some(original_modules)) => {
+                        compiler::compile_mutant(options, &path, &mutant_path).map(|modules| {
+                            let compiles = modules.is_some();
+                            (compiles, modules.is_some_and(|m| m == *original_modules))
+                        })
+                    },
+                    (Some(options), None) => compiler::verify_mutant(options, &path, &mutant_path)
+                        .map(|compiles| (compiles, false)),
+                    (None, _) => Ok((true, false)),
+                };
+
+                let is_equivalent = match compilation {
+                    Ok((false, _)) if verify_mutants => {
+                        info!("job_{rayon_tid}: {mutant} does not compile, discarding it");
+                        discarded_mutants.fetch_add(1, Ordering::Relaxed);
+                        let _ = fs::remove_file(&mutant_path);
+                        return None;
+                    },
+                    Ok((_, is_equivalent)) => is_equivalent,
+                    Err(e) => {
+                        warn!("job_{rayon_tid}: cannot compile {mutant}: {e}");
+                        false
+                    },
+                };
+
+                if is_equivalent {
+                    info!(
+                        "job_{rayon_tid}: {mutant} is equivalent to the original code, dropping it"
+                    );
+                    let _ = fs::remove_file(&mutant_path);
+                }
+
+                let mut entry = report::MutationReport::new(
+                    mutant_path.as_path(),
+                    &path,
+                    &module,
+                    &function,
+                    &mutated_info.mutated_source,
+                    original_source,
+                );
+
+                entry.set_mutant_id(stable_id);
+                entry.add_modification(mutated_info.mutation);
+                for duplicate in duplicates {
+                    entry.add_duplicate_modification(duplicate);
+                }
+                Some((entry, is_equivalent))
+            },
+        )
         .flatten()
         .collect();
 
+    if verify_mutants {
+        println!(
+            "Discarded {} mutants which do not compile",
+            discarded_mutants.into_inner()
+        );
+    }
+
     let mut report: Report = Report::new();
-    for entry in mutation_reports {
-        report.add_entry(entry);
+    for (entry, is_equivalent) in mutation_reports {
+        if is_equivalent {
+            report.add_equivalent_entry(entry);
+        } else {
+            report.add_entry(entry);
+        }
+    }
+
+    if original_modules.is_some() {
+        println!(
+            "Dropped {} mutants equivalent to the original code",
+            report.get_equivalent_mutants().len()
+        );
     }
 
     trace!("Saving reports to: {output_dir:?}");
@@ -13,6 +13,7 @@ pub struct Mutant {
     operator: MutationOp,
     module_name: Option<String>,
     function_name: Option<String>,
+    function_start: usize,
 }
 
 impl Mutant {
@@ -24,6 +25,7 @@ impl Mutant {
             operator,
             module_name: None,
             function_name: None,
+            function_start: 0,
         }
     }
 
@@ -58,6 +60,17 @@ impl Mutant {
     pub fn set_function_name(&mut self, function_name: String) {
         self.function_name = Some(function_name);
     }
+
+    /// Returns the byte offset of the start of the function that this mutant is in.
+    pub fn get_function_start(&self) -> usize {
+        self.function_start
+    }
+
+    /// Sets the byte offset of the start of the function that this mutant is in.
+    /// The stable identity of the mutant is computed relative to this offset.
+    pub fn set_function_start(&mut self, function_start: usize) {
+        self.function_start = function_start;
+    }
 }
 
 impl fmt::Display for Mutant {
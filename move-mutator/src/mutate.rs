@@ -7,19 +7,41 @@ use crate::{
     configuration::Configuration,
     mutant::Mutant,
     operator::MutationOp,
-    operator_filter,
+    operator_filter::{self, OperatorMode},
     operators::{
-        binary::Binary, binary_swap::BinarySwap, break_continue::BreakContinue,
-        delete_stmt::DeleteStmt, ifelse::IfElse, literal::Literal, unary::Unary, ExpLoc,
+        abort_code::{split_error_category, AbortCode},
+        assert::AssertReplacement,
+        binary::Binary,
+        binary_swap::BinarySwap,
+        break_continue::BreakContinue,
+        delete_stmt::DeleteStmt,
+        ifelse::IfElse,
+        literal::Literal,
+        return_value::ReturnValue,
+        spec_condition::SpecCondition,
+        spec_old::SpecOld,
+        unary::Unary,
+        ExpLoc,
     },
 };
+use anyhow::Context;
 use move_model::{
-    ast::{Attribute, Exp, ExpData, Operation},
-    model::{FunctionEnv, GlobalEnv, ModuleEnv},
-    symbol::SymbolPool,
+    ast::{Attribute, ConditionKind, Exp, ExpData, Operation, Spec},
+    model::{FunctionEnv, GlobalEnv, Loc, ModuleEnv, NodeId, Parameter},
+    symbol::{Symbol, SymbolPool},
+    ty::{PrimitiveType, Type},
 };
 use move_package::source_package::layout::SourcePackageLayout;
-use std::path::Path;
+use std::{collections::BTreeSet, path::Path};
+
+/// The attribute which disables the mutation of the module or function, or only the given operators.
+const SKIP_ATTRIBUTE: &str = "mutation::skip";
+/// The function attribute which enables only the given operators.
+const ONLY_ATTRIBUTE: &str = "mutation::only";
+/// The pseudo-function name of the module specification mutants.
+const MODULE_SPEC_NAME: &str = "<spec module>";
+/// The module defining the `Option` type, whose returned values are replaced with `none()`.
+const STD_OPTION_MODULE: &str = "0x1::option";
 
 /// Traverses the AST, identifies places where mutation operators can be applied
 /// and returns a list of mutants.
@@ -76,11 +98,15 @@ fn traverse_module_with_check(
     }
 
     // Now we need to check if the module is included in the configuration.
-    if let cli::ModuleFilter::Selected(mods) = &conf.project.mutate_modules {
-        if !mods.contains(&module_name) {
-            trace!("Skipping module {module_name}");
-            return Ok(vec![]);
-        }
+    if !conf
+        .project
+        .mutate_modules
+        .as_ref()
+        .unwrap_or(&cli::ModuleFilter::All)
+        .selects(conf.project.exclude_modules.as_ref(), &module_name)
+    {
+        trace!("Skipping module {module_name}");
+        return Ok(vec![]);
     }
 
     traverse_module(module, conf)
@@ -94,18 +120,37 @@ fn traverse_module(module: &ModuleEnv<'_>, conf: &Configuration) -> anyhow::Resu
 
     let attrs = module.get_attributes();
     let sym_pool = module.symbol_pool();
-    if let Some(attr) = contains_attribute(attrs, sym_pool, &["mutation::skip"]) {
-        trace!("Skipping module {module_name} attributed with the {attr} attribute");
+    let skipped = attribute_operators(attrs, sym_pool, SKIP_ATTRIBUTE)
+        .with_context(|| format!("invalid attribute of module {module_name}"))?;
+    if skipped.as_ref().is_some_and(Vec::is_empty) {
+        trace!("Skipping module {module_name} attributed with the {SKIP_ATTRIBUTE} attribute");
         return Ok(vec![]);
     }
+    let operator_mode = conf
+        .operator_mode
+        .restrict(&skipped.unwrap_or_default(), None);
+
+    // The error constants are found in all functions of the module, so they are collected once.
+    let error_codes = if operator_mode.should_apply(operator_filter::Operator::AbortCodeReplacement)
+    {
+        error_constants(module)
+    } else {
+        vec![]
+    };
 
     trace!("Traversing module {}", &module_name);
     let mut mutants = module
         .get_functions()
-        .map(|func| traverse_function(&func, conf))
+        .map(|func| traverse_function(&func, conf, &operator_mode, &error_codes))
         .collect::<Result<Vec<_>, _>>()?
         .concat();
 
+    if conf.project.mutate_specs {
+        mutants.extend(traverse_module_specs(module, conf));
+    } else {
+        mutants.extend(traverse_constants(module, conf, &operator_mode));
+    }
+
     // Set the module name for all the mutants.
     mutants
         .iter_mut()
@@ -118,38 +163,316 @@ fn traverse_module(module: &ModuleEnv<'_>, conf: &Configuration) -> anyhow::Resu
     Ok(mutants)
 }
 
+/// Traverses the named constants of the module and returns a list of mutants.
+/// The mutants are attributed to the `<const NAME>` pseudo-functions.
+fn traverse_constants(
+    module: &ModuleEnv<'_>,
+    conf: &Configuration,
+    operator_mode: &OperatorMode,
+) -> Vec<Mutant> {
+    // Constants don't belong to any function, so they are mutated whenever their module is,
+    // regardless of the coverage and the specifications of the functions.
+    if !operator_mode.should_apply(operator_filter::Operator::LiteralReplacement) {
+        return vec![];
+    }
+
+    let module_name = module.symbol_pool().string(module.get_name().name());
+    module
+        .get_named_constants()
+        .filter_map(|constant| {
+            let name = module.symbol_pool().string(constant.get_name()).to_string();
+            let function_name = format!("<const {name}>");
+            if !selects_function(conf, &module_name, &function_name) {
+                trace!("Skipping constant {module_name}::{name}");
+                return None;
+            }
+
+            let Some(value_loc) = constant_value_loc(module.env, &constant.get_loc(), &name) else {
+                trace!("Skipping constant {name} as its value cannot be located");
+                return None;
+            };
+
+            trace!("Traversing constant {name}");
+            let mut mutant = Mutant::new(MutationOp::new(Box::new(Literal::new(
+                constant.get_value(),
+                constant.get_type(),
+                value_loc,
+            ))));
+            mutant.set_function_name(function_name);
+            mutant.set_function_start(constant.get_loc().span().start().to_usize());
+            Some(mutant)
+        })
+        .collect()
+}
+
+/// Traverses the module and struct specifications of the module and returns a list of mutants.
+/// The mutants are attributed to the `<spec module>` and `<spec STRUCT>` pseudo-functions.
+fn traverse_module_specs(module: &ModuleEnv<'_>, conf: &Configuration) -> Vec<Mutant> {
+    let module_name = module.symbol_pool().string(module.get_name().name());
+
+    // Module and struct specifications don't belong to any function, so they are selected by
+    // their pseudo-function names.
+    let mut mutants = vec![];
+    if selects_function(conf, &module_name, MODULE_SPEC_NAME) {
+        mutants = find_spec_mutants(module, &module.get_spec());
+        let module_start = module.get_loc().span().start().to_usize();
+        mutants.iter_mut().for_each(|m| {
+            m.set_function_name(MODULE_SPEC_NAME.to_owned());
+            m.set_function_start(module_start);
+        });
+    }
+
+    for struct_env in module.get_structs() {
+        let name = module
+            .symbol_pool()
+            .string(struct_env.get_name())
+            .to_string();
+        if !selects_function(conf, &module_name, &format!("<spec {name}>")) {
+            trace!("Skipping specification of struct {module_name}::{name}");
+            continue;
+        }
+        trace!("Traversing specification of struct {name}");
+
+        let mut struct_mutants = find_spec_mutants(module, &struct_env.get_spec());
+        let struct_start = struct_env.get_loc().span().start().to_usize();
+        struct_mutants.iter_mut().for_each(|m| {
+            m.set_function_name(format!("<spec {name}>"));
+            m.set_function_start(struct_start);
+        });
+        mutants.extend(struct_mutants);
+    }
+
+    mutants
+}
+
+/// Checks whether the function is selected by the function filters of the configuration.
+///
+/// The constants and the module and struct specifications are checked with their pseudo-function
+/// names, e.g. `<const NAME>` or `<spec module>`.
+fn selects_function(conf: &Configuration, module_name: &str, function_name: &str) -> bool {
+    conf.project
+        .mutate_functions
+        .as_ref()
+        .unwrap_or(&cli::FunctionFilter::All)
+        .selects(
+            conf.project.exclude_functions.as_ref(),
+            module_name,
+            function_name,
+        )
+}
+
+/// Finds the mutants of the specification conditions, e.g. `ensures`, `aborts_if` or invariants.
+fn find_spec_mutants(module: &ModuleEnv<'_>, spec: &Spec) -> Vec<Mutant> {
+    let env = module.env;
+    let exp_loc = |exp: &Exp| ExpLoc {
+        exp: exp.clone(),
+        loc: env.get_node_loc(exp.node_id()),
+    };
+
+    let mut result = vec![];
+    // A schema included several times adds its conditions with the same location each time.
+    let mut mutated_conditions = BTreeSet::new();
+    for cond in &spec.conditions {
+        // Conditions included from the schemas in other files cannot be mutated here.
+        if cond.loc.file_id() != module.get_loc().file_id() {
+            continue;
+        }
+        let span = cond.loc.span();
+        if !mutated_conditions.insert((span.start().to_usize(), span.end().to_usize())) {
+            continue;
+        }
+
+        let is_boolean = matches!(
+            cond.kind,
+            ConditionKind::Assert
+                | ConditionKind::Assume
+                | ConditionKind::AbortsIf
+                | ConditionKind::SucceedsIf
+                | ConditionKind::Ensures
+                | ConditionKind::Requires
+                | ConditionKind::StructInvariant
+                | ConditionKind::LoopInvariant
+                | ConditionKind::GlobalInvariant(..)
+                | ConditionKind::GlobalInvariantUpdate(..)
+        );
+        // Let bindings, axioms and other declarations are not conditions which can be mutated.
+        // Neither are `aborts_with`, `modifies` and `emits`, which could only be dropped.
+        if !is_boolean {
+            continue;
+        }
+
+        // Dropping or weakening an obligation (e.g. `ensures`) can never break the proof, so only
+        // the conditions which the specification relies on are dropped and weakened.
+        let is_droppable = matches!(
+            cond.kind,
+            ConditionKind::Assume
+                | ConditionKind::AbortsIf
+                | ConditionKind::Requires
+                | ConditionKind::StructInvariant
+                | ConditionKind::LoopInvariant
+                | ConditionKind::GlobalInvariant(..)
+                | ConditionKind::GlobalInvariantUpdate(..)
+        );
+
+        let conjuncts = match cond.exp.as_ref() {
+            ExpData::Call(_, Operation::And, args) => args.iter().map(exp_loc).collect(),
+            _ => vec![],
+        };
+        result.push(Mutant::new(MutationOp::new(Box::new(SpecCondition::new(
+            cond.loc.clone(),
+            Some(exp_loc(&cond.exp)),
+            conjuncts,
+            is_droppable,
+        )))));
+
+        for exp in std::iter::once(&cond.exp).chain(cond.additional_exps.iter()) {
+            exp.visit_pre_order(&mut |exp_data| {
+                if let ExpData::Call(node_id, Operation::Old, args) = exp_data {
+                    if let Some(arg) = args.first() {
+                        result.push(Mutant::new(MutationOp::new(Box::new(SpecOld::new(
+                            env.get_node_loc(*node_id),
+                            exp_loc(arg),
+                        )))));
+                    }
+                }
+                true
+            });
+        }
+    }
+
+    result
+}
+
+/// Finds the location of the value in the constant declaration, e.g. `30` in
+/// `const FEE_BPS: u64 = 30;`.
+///
+/// The model doesn't keep the location of the value expression, so the declaration is located
+/// by the constant name taken from the model. The value is the expression after the `=` which
+/// follows the `const NAME:` prefix, so that the attributes before the declaration are never
+/// taken for the value.
+fn constant_value_loc(env: &GlobalEnv, loc: &Loc, name: &str) -> Option<Loc> {
+    let declaration = env.get_source(loc).ok()?;
+    let name_end = declaration_name_end(declaration, name)?;
+    let type_start = name_end + declaration[name_end..].find(':')? + 1;
+    let value_start = type_start + declaration[type_start..].find('=')? + 1;
+    let value = declaration[value_start..].trim_end();
+    let value = value.strip_suffix(';').unwrap_or(value).trim_end();
+
+    // Keep the `@` of the address values, as the replacements are plain numbers.
+    let trimmed = value.trim_start().trim_start_matches('@');
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let start = loc.span().start().to_usize() + value_start + (value.len() - trimmed.len());
+    let end = start + trimmed.len();
+    Some(Loc::new(
+        loc.file_id(),
+        codespan::Span::new(start as u32, end as u32),
+    ))
+}
+
+/// Returns the offset right after the `NAME` in the `const NAME` prefix of the declaration.
+fn declaration_name_end(declaration: &str, name: &str) -> Option<usize> {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    declaration
+        .match_indices("const")
+        .find_map(|(idx, keyword)| {
+            let preceded_by_ident = declaration[..idx]
+                .chars()
+                .next_back()
+                .is_some_and(is_ident_char);
+            let rest = &declaration[idx + keyword.len()..];
+            let name_offset = rest.len() - rest.trim_start().len();
+            if preceded_by_ident || name_offset == 0 || !rest[name_offset..].starts_with(name) {
+                return None;
+            }
+
+            let name_end = idx + keyword.len() + name_offset + name.len();
+            let followed_by_ident = declaration[name_end..]
+                .chars()
+                .next()
+                .is_some_and(is_ident_char);
+            (!followed_by_ident).then_some(name_end)
+        })
+}
+
 /// Traverses a single function and returns a list of mutants.
 /// Checks the body of the function by traversing its definition.
+///
+/// The `operator_mode` contains the operators enabled for the module, which are further
+/// restricted by the `#[mutation::skip(...)]` and `#[mutation::only(...)]` function attributes.
+/// The `error_codes` are the error constants of the module which can replace the abort codes.
 fn traverse_function(
     function: &FunctionEnv<'_>,
     conf: &Configuration,
+    operator_mode: &OperatorMode,
+    error_codes: &[String],
 ) -> anyhow::Result<Vec<Mutant>> {
     let function_name = &function.get_name_str();
 
     let attrs = function.get_attributes();
     let sym_pool = function.module_env.symbol_pool();
-    if let Some(attr) = contains_attribute(attrs, sym_pool, &["test", "mutation::skip"]) {
+    if let Some(attr) = contains_attribute(attrs, sym_pool, &["test"]) {
         trace!("Skipping function {function_name} attributed with the {attr} attribute");
         return Ok(vec![]);
     }
 
-    let mut included_funcs = vec![];
-
-    // Check if any function is included in the general project configuration.
-    if let cli::FunctionFilter::Selected(funcs) = &conf.project.mutate_functions {
-        included_funcs = included_funcs.into_iter().chain(funcs.iter()).collect();
+    let skipped = attribute_operators(attrs, sym_pool, SKIP_ATTRIBUTE)
+        .with_context(|| format!("invalid attribute of function {function_name}"))?;
+    if skipped.as_ref().is_some_and(Vec::is_empty) {
+        trace!("Skipping function {function_name} attributed with the {SKIP_ATTRIBUTE} attribute");
+        return Ok(vec![]);
+    }
+    let only = attribute_operators(attrs, sym_pool, ONLY_ATTRIBUTE)
+        .with_context(|| format!("invalid attribute of function {function_name}"))?;
+    if only.as_ref().is_some_and(Vec::is_empty) {
+        anyhow::bail!(
+            "the {ONLY_ATTRIBUTE} attribute of function {function_name} requires at least one operator"
+        );
     }
+    let operator_mode = operator_mode.restrict(&skipped.unwrap_or_default(), only.as_deref());
 
     // Mutate only the specified functions, if any. Otherwise, mutate all functions.
-    if !included_funcs.is_empty() && !included_funcs.contains(&function_name) {
-        trace!("Skipping function {function_name}");
+    let module_name = sym_pool.string(function.module_env.get_name().name());
+    if !selects_function(conf, &module_name, function_name) {
+        trace!("Skipping function {module_name}::{function_name}");
         return Ok(vec![]);
     }
 
+    if conf.project.only_with_specs && !has_spec_conditions(function) {
+        trace!("Skipping function {function_name} without specifications");
+        return Ok(vec![]);
+    }
+
+    if conf.project.mutate_specs {
+        trace!("Traversing specification of function {function_name}");
+        let mut result = find_spec_mutants(&function.module_env, &function.get_spec());
+
+        // Specifications inside the function body, e.g. assertions or loop invariants.
+        if let Some(exp) = function.get_def() {
+            exp.visit_pre_order(&mut |exp_data| {
+                if let ExpData::SpecBlock(_, spec) = exp_data {
+                    result.extend(find_spec_mutants(&function.module_env, spec));
+                }
+                true
+            });
+        }
+
+        set_function(&mut result, function);
+        return Ok(result);
+    }
+
     trace!("Traversing function {function_name}");
     let mut result = Vec::<Mutant>::new();
     let mut is_inside_spec = false;
     if let Some(exp) = function.get_def() {
+        // Expressions which produce the value returned from the function body.
+        let mut result_nodes = BTreeSet::new();
+        collect_result_nodes(exp, &mut result_nodes);
+        let reassignments = collect_reassignments(function, exp);
+
         exp.visit_pre_post(&mut |asc, exp_data| {
             // Collect the spec blocks locations.
             if let ExpData::SpecBlock(_, _) = exp_data {
@@ -169,26 +492,71 @@ fn traverse_function(
                 return true;
             }
 
-            result.extend(parse_expression_and_find_mutants(function, exp_data, conf));
+            result.extend(parse_expression_and_find_mutants(
+                function,
+                exp_data,
+                &operator_mode,
+                error_codes,
+                &reassignments,
+            ));
+            if result_nodes.contains(&exp_data.node_id()) {
+                result.extend(find_return_value_mutants(
+                    function,
+                    exp_data,
+                    &operator_mode,
+                ));
+            }
             true
         });
     };
 
-    result
-        .iter_mut()
-        .for_each(|m| m.set_function_name(function_name.clone()));
+    set_function(&mut result, function);
 
     Ok(result)
 }
 
+/// Sets the name and the start of the function for all the mutants found in it.
+fn set_function(mutants: &mut [Mutant], function: &FunctionEnv<'_>) {
+    let function_name = function.get_name_str();
+    let function_start = function.get_loc().span().start().to_usize();
+    mutants.iter_mut().for_each(|m| {
+        m.set_function_name(function_name.clone());
+        m.set_function_start(function_start);
+    });
+}
+
+/// Checks if the function has any specification conditions, either in its `spec` block or inside
+/// its body.
+fn has_spec_conditions(function: &FunctionEnv<'_>) -> bool {
+    if !function.get_spec().conditions.is_empty() {
+        return true;
+    }
+
+    let mut found = false;
+    if let Some(exp) = function.get_def() {
+        exp.visit_pre_order(&mut |exp_data| {
+            if let ExpData::SpecBlock(_, spec) = exp_data {
+                found |= !spec.conditions.is_empty();
+            }
+            !found
+        });
+    }
+    found
+}
+
 /// This function does the actual parsing of the expression and checks if any of the mutation operators
 /// can be applied to it.
 /// When Move language is extended with new expressions, this function needs to be updated to support them.
+///
+/// The `reassignments` are the assignments of the function which can be deleted, see
+/// [`collect_reassignments`].
 #[allow(clippy::too_many_lines)]
 fn parse_expression_and_find_mutants(
     function: &FunctionEnv<'_>,
     exp: &ExpData,
-    conf: &Configuration,
+    operator_mode: &OperatorMode,
+    error_codes: &[String],
+    reassignments: &BTreeSet<NodeId>,
 ) -> Vec<Mutant> {
     let convert_exps_to_explocs = |exps: &[Exp]| -> Vec<ExpLoc> {
         exps.iter()
@@ -223,16 +591,28 @@ fn parse_expression_and_find_mutants(
     match exp {
         ExpData::Call(node_id, op, exps) => match op {
             Operation::MoveTo | Operation::Abort => {
-                if !conf
-                    .operator_mode
-                    .should_apply(operator_filter::Operator::DeleteStatement)
+                let mut result = Vec::new();
+
+                if operator_mode.should_apply(operator_filter::Operator::DeleteStatement) {
+                    result.push(Mutant::new(MutationOp::new(Box::new(DeleteStmt::new(
+                        exp.clone().into_exp(),
+                        function.module_env.env.get_node_loc(*node_id),
+                    )))));
+                }
+
+                if matches!(op, Operation::Abort)
+                    && operator_mode.should_apply(operator_filter::Operator::AbortCodeReplacement)
                 {
-                    return vec![];
+                    if let Some(code) = convert_exps_to_explocs(exps).into_iter().next() {
+                        result.push(Mutant::new(MutationOp::new(Box::new(AbortCode::new(
+                            code,
+                            function.module_env.env.get_node_loc(*node_id),
+                            error_codes.to_vec(),
+                        )))));
+                    }
                 }
-                vec![Mutant::new(MutationOp::new(Box::new(DeleteStmt::new(
-                    exp.clone().into_exp(),
-                    function.module_env.env.get_node_loc(*node_id),
-                ))))]
+
+                result
             },
             Operation::Add
             | Operation::Sub
@@ -255,9 +635,7 @@ fn parse_expression_and_find_mutants(
                 let exps_loc = convert_exps_to_explocs(exps);
                 let mut result = Vec::new();
 
-                if conf
-                    .operator_mode
-                    .should_apply(operator_filter::Operator::BinaryOperatorReplacement)
+                if operator_mode.should_apply(operator_filter::Operator::BinaryOperatorReplacement)
                 {
                     result.push(Mutant::new(MutationOp::new(Box::new(Binary::new(
                         op.clone(),
@@ -266,10 +644,7 @@ fn parse_expression_and_find_mutants(
                     )))));
                 }
 
-                if conf
-                    .operator_mode
-                    .should_apply(operator_filter::Operator::BinaryOperatorSwap)
-                {
+                if operator_mode.should_apply(operator_filter::Operator::BinaryOperatorSwap) {
                     result.push(Mutant::new(MutationOp::new(Box::new(BinarySwap::new(
                         op.clone(),
                         function.module_env.env.get_node_loc(*node_id),
@@ -280,9 +655,7 @@ fn parse_expression_and_find_mutants(
                 result
             },
             Operation::Not => {
-                if !conf
-                    .operator_mode
-                    .should_apply(operator_filter::Operator::UnaryOperatorReplacement)
+                if !operator_mode.should_apply(operator_filter::Operator::UnaryOperatorReplacement)
                 {
                     return vec![];
                 }
@@ -296,35 +669,50 @@ fn parse_expression_and_find_mutants(
             _ => vec![],
         },
         ExpData::IfElse(_, cond, if_exp, else_exp) => {
-            if !conf
-                .operator_mode
-                .should_apply(operator_filter::Operator::IfElseReplacement)
-            {
-                return vec![];
-            }
+            let mut result = Vec::new();
             let cond_loc = ExpLoc {
                 exp: cond.clone(),
                 loc: function.module_env.env.get_node_loc(cond.node_id()),
             };
-            let if_exp_loc = ExpLoc {
-                exp: if_exp.clone(),
-                loc: function.module_env.env.get_node_loc(if_exp.node_id()),
-            };
-            let else_exp_loc = ExpLoc {
-                exp: else_exp.clone(),
-                loc: function.module_env.env.get_node_loc(else_exp.node_id()),
-            };
-            vec![Mutant::new(MutationOp::new(Box::new(IfElse::new(
-                cond_loc,
-                if_exp_loc,
-                else_exp_loc,
-            ))))]
+
+            if operator_mode.should_apply(operator_filter::Operator::IfElseReplacement) {
+                let if_exp_loc = ExpLoc {
+                    exp: if_exp.clone(),
+                    loc: function.module_env.env.get_node_loc(if_exp.node_id()),
+                };
+                let else_exp_loc = ExpLoc {
+                    exp: else_exp.clone(),
+                    loc: function.module_env.env.get_node_loc(else_exp.node_id()),
+                };
+                result.push(Mutant::new(MutationOp::new(Box::new(IfElse::new(
+                    cond_loc.clone(),
+                    if_exp_loc,
+                    else_exp_loc,
+                )))));
+            }
+
+            // The `assert!` macro is expanded to `if (cond) () else abort code`. Its abort code is
+            // replaced while parsing the abort expression, and its condition is already replaced
+            // with `true` by the if/else replacement operator, if enabled.
+            if let ExpData::Call(abort_id, Operation::Abort, abort_exps) = else_exp.as_ref() {
+                if operator_mode.should_apply(operator_filter::Operator::AssertReplacement) {
+                    let code_loc = convert_exps_to_explocs(abort_exps).into_iter().next();
+                    result.push(Mutant::new(MutationOp::new(Box::new(
+                        AssertReplacement::new(
+                            cond_loc,
+                            function.module_env.env.get_node_loc(*abort_id),
+                            code_loc,
+                            !operator_mode
+                                .should_apply(operator_filter::Operator::IfElseReplacement),
+                        ),
+                    ))));
+                }
+            }
+
+            result
         },
         ExpData::Value(node_id, value) => {
-            if !conf
-                .operator_mode
-                .should_apply(operator_filter::Operator::LiteralReplacement)
-            {
+            if !operator_mode.should_apply(operator_filter::Operator::LiteralReplacement) {
                 return vec![];
             }
             let mutants = vec![Mutant::new(MutationOp::new(Box::new(Literal::new(
@@ -335,19 +723,30 @@ fn parse_expression_and_find_mutants(
             mutants
         },
         ExpData::LoopCont(node_id, ..) => {
-            if !conf
-                .operator_mode
-                .should_apply(operator_filter::Operator::BreakContinueReplacement)
-            {
+            if !operator_mode.should_apply(operator_filter::Operator::BreakContinueReplacement) {
                 return vec![];
             }
             vec![Mutant::new(MutationOp::new(Box::new(BreakContinue::new(
                 function.module_env.env.get_node_loc(*node_id),
             ))))]
         },
+        ExpData::Return(_, value) => find_return_value_mutants(function, value, operator_mode),
+        ExpData::Sequence(_, exps) => {
+            if !operator_mode.should_apply(operator_filter::Operator::DeleteStatement) {
+                return vec![];
+            }
+            exps.iter()
+                .filter(|e| is_deletable_statement(function, e, reassignments))
+                .map(|e| {
+                    Mutant::new(MutationOp::new(Box::new(DeleteStmt::new(
+                        e.clone(),
+                        function.module_env.env.get_node_loc(e.node_id()),
+                    ))))
+                })
+                .collect()
+        },
 
-        ExpData::Return(..)
-        | ExpData::Mutate(..)
+        ExpData::Mutate(..)
         | ExpData::Assign(..)
         | ExpData::Block(..)
         | ExpData::Invoke(..)
@@ -356,13 +755,205 @@ fn parse_expression_and_find_mutants(
         | ExpData::Loop(..)
         | ExpData::Temporary(..)
         | ExpData::SpecBlock(..)
-        | ExpData::Sequence(..)
         | ExpData::Quant(..)
         | ExpData::Match(..)
         | ExpData::Invalid(..) => vec![],
     }
 }
 
+/// Checks if the statement from a sequence can be deleted without affecting the compilation.
+///
+/// Assignments to the already initialized variables and calls to functions which don't return
+/// any value are deletable. The `move_to` and `abort` calls are handled separately while parsing
+/// the expressions.
+fn is_deletable_statement(
+    function: &FunctionEnv<'_>,
+    exp: &ExpData,
+    reassignments: &BTreeSet<NodeId>,
+) -> bool {
+    let env = function.module_env.env;
+    match exp {
+        // Assignments generated by the compiler for the desugared constructs (e.g. loops) don't
+        // have the target placed before the value in the source code.
+        ExpData::Assign(node_id, pattern, value) => {
+            reassignments.contains(node_id)
+                && env.get_node_loc(pattern.node_id()).span().end()
+                    <= env.get_node_loc(value.node_id()).span().start()
+        },
+        ExpData::Mutate(_, target, value) => {
+            env.get_node_loc(target.node_id()).span().end()
+                <= env.get_node_loc(value.node_id()).span().start()
+        },
+        // Without the call, the values moved into it would have to be dropped.
+        ExpData::Call(node_id, Operation::MoveFunction(..), args) => {
+            env.get_node_type(*node_id).is_unit()
+                && !args
+                    .iter()
+                    .any(|arg| moves_undroppable_value(function, arg))
+        },
+        ExpData::Invoke(node_id, _, args) => {
+            env.get_node_type(*node_id).is_unit()
+                && !args
+                    .iter()
+                    .any(|arg| moves_undroppable_value(function, arg))
+        },
+        _ => false,
+    }
+}
+
+/// Collects the assignments to the variables which are initialized before, i.e. the function
+/// parameters and the variables declared with a value.
+///
+/// Deleting the assignment which initializes a variable declared without a value (e.g. `x = 1`
+/// after `let x;`) leaves the variable unassigned, so such a mutant would never compile. The
+/// variables declared without a value are never considered initialized, even after they are
+/// assigned on all paths.
+fn collect_reassignments(function: &FunctionEnv<'_>, exp: &ExpData) -> BTreeSet<NodeId> {
+    // The variables in scope with the flag telling whether they are initialized. The innermost
+    // declaration of a variable is the last one.
+    let mut variables: Vec<(Symbol, bool)> = function
+        .get_parameters()
+        .into_iter()
+        .map(|Parameter(name, ..)| (name, true))
+        .collect();
+    let mut scopes = vec![];
+    let mut reassignments = BTreeSet::new();
+
+    exp.visit_pre_post(&mut |asc, exp_data| {
+        match exp_data {
+            ExpData::Block(_, pattern, binding, _) if !asc => {
+                scopes.push(variables.len());
+                variables.extend(
+                    pattern
+                        .vars()
+                        .into_iter()
+                        .map(|(_, name)| (name, binding.is_some())),
+                );
+            },
+            ExpData::Block(..) => variables.truncate(scopes.pop().unwrap_or_default()),
+            ExpData::Assign(node_id, pattern, _) if !asc => {
+                let initialized = pattern.vars().iter().all(|(_, name)| {
+                    variables
+                        .iter()
+                        .rev()
+                        .find(|(variable, _)| variable == name)
+                        .is_some_and(|(_, initialized)| *initialized)
+                });
+                if initialized {
+                    reassignments.insert(*node_id);
+                }
+            },
+            _ => {},
+        }
+        true
+    });
+
+    reassignments
+}
+
+/// Checks if the expression moves a local variable whose type doesn't have the drop ability.
+fn moves_undroppable_value(function: &FunctionEnv<'_>, exp: &ExpData) -> bool {
+    match exp {
+        ExpData::LocalVar(node_id, _) | ExpData::Temporary(node_id, _) => {
+            let env = function.module_env.env;
+            let ty = env.get_node_type(*node_id);
+            !env.type_abilities(&ty, &function.get_type_parameters())
+                .has_drop()
+        },
+        ExpData::Call(_, Operation::Move, args) => args
+            .iter()
+            .any(|arg| moves_undroppable_value(function, arg)),
+        _ => false,
+    }
+}
+
+/// Returns the names of the error constants defined in the module.
+///
+/// Error constants are the `u64` constants used as abort codes in the module, either directly or
+/// wrapped with an error category, e.g. `error::not_found(ENOT_FOUND)`.
+fn error_constants(module: &ModuleEnv<'_>) -> Vec<String> {
+    let env = module.env;
+    let mut abort_codes = BTreeSet::new();
+    for function in module.get_functions() {
+        let Some(exp) = function.get_def() else {
+            continue;
+        };
+        exp.visit_pre_order(&mut |exp_data| {
+            if let ExpData::Call(_, Operation::Abort, args) = exp_data {
+                if let Some(code) = args.first() {
+                    let loc = env.get_node_loc(code.node_id());
+                    let source = env.get_file_source(loc.file_id());
+                    let code = &source[loc.span().start().to_usize()..loc.span().end().to_usize()];
+                    let code = split_error_category(code).map_or(code, |(_, _, inner)| inner);
+                    abort_codes.insert(code.trim().to_owned());
+                }
+            }
+            true
+        });
+    }
+
+    module
+        .get_named_constants()
+        .filter(|c| c.get_type() == Type::Primitive(PrimitiveType::U64))
+        .map(|c| module.symbol_pool().string(c.get_name()).to_string())
+        .filter(|name| abort_codes.contains(name))
+        .collect()
+}
+
+/// Collects the node ids of the expressions which produce the result of the function body.
+///
+/// Explicit `return` expressions are handled separately while parsing the expressions.
+fn collect_result_nodes(exp: &ExpData, nodes: &mut BTreeSet<NodeId>) {
+    match exp {
+        ExpData::Sequence(_, exps) => {
+            if let Some(last) = exps.last() {
+                collect_result_nodes(last, nodes);
+            }
+        },
+        ExpData::Block(_, _, _, body) => collect_result_nodes(body, nodes),
+        ExpData::Return(..) | ExpData::Call(_, Operation::Abort, _) => {},
+        _ => {
+            nodes.insert(exp.node_id());
+        },
+    }
+}
+
+/// Creates the return value replacement mutant for the expression whose value is returned from
+/// the function.
+fn find_return_value_mutants(
+    function: &FunctionEnv<'_>,
+    exp: &ExpData,
+    operator_mode: &OperatorMode,
+) -> Vec<Mutant> {
+    if !operator_mode.should_apply(operator_filter::Operator::ReturnValueReplacement) {
+        return vec![];
+    }
+
+    let env = function.module_env.env;
+    let node_id = exp.node_id();
+    let optype = env.get_node_type(node_id);
+
+    // Unit and tuple results cannot be replaced with a single value.
+    if let Type::Tuple(_) = optype {
+        return vec![];
+    }
+
+    // User modules can be named `option` as well, so the address is checked too.
+    let is_option = if let Type::Struct(module_id, struct_id, _) = &optype {
+        let struct_env = env.get_struct(module_id.qualified(*struct_id));
+        struct_env.module_env.get_full_name_str() == STD_OPTION_MODULE
+            && env.symbol_pool().string(struct_env.get_name()).as_str() == "Option"
+    } else {
+        false
+    };
+
+    vec![Mutant::new(MutationOp::new(Box::new(ReturnValue::new(
+        optype,
+        is_option,
+        env.get_node_loc(node_id),
+    ))))]
+}
+
 /// Returns the first contained attribute if any.
 fn contains_attribute<'a>(
     attributes: &[Attribute],
@@ -378,3 +969,31 @@ fn contains_attribute<'a>(
         .find(|&target_attr| attrs.iter().any(|attr| attr.as_str() == *target_attr))
         .copied()
 }
+
+/// Returns the operators given as the arguments of the attribute, if the attribute is present.
+///
+/// The attribute without arguments (e.g. `#[mutation::skip]`) results in an empty list.
+fn attribute_operators(
+    attributes: &[Attribute],
+    symbol_pool: &SymbolPool,
+    target: &str,
+) -> anyhow::Result<Option<Vec<operator_filter::Operator>>> {
+    let Some(attr) = attributes
+        .iter()
+        .find(|attr| attr.name().display(symbol_pool).to_string() == target)
+    else {
+        return Ok(None);
+    };
+
+    let names = match attr {
+        Attribute::Apply(_, _, args) => args
+            .iter()
+            .map(|arg| arg.name().display(symbol_pool).to_string())
+            .collect::<Vec<_>>(),
+        Attribute::Assign(..) => anyhow::bail!("the {target} attribute cannot have a value"),
+    };
+
+    OperatorMode::parse_operators(&names)
+        .map(Some)
+        .with_context(|| format!("invalid arguments of the {target} attribute"))
+}
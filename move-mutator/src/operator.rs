@@ -5,6 +5,7 @@
 use crate::report::Mutation;
 use ahash::RandomState;
 use codespan::FileId;
+use mutator_common::hash::stable_hash;
 use std::{
     fmt,
     fmt::{Debug, Display},
@@ -34,6 +35,37 @@ impl MutantInfo {
         let fixed_randomness = RandomState::with_seeds(1, 2, 3, 4);
         fixed_randomness.hash_one(self)
     }
+
+    /// Calculates the stable identity of the mutant.
+    ///
+    /// Unlike [`MutantInfo::unique_id`], the identity doesn't depend on the rest of the source,
+    /// as it's built from the module, the function, the operator, the old and new values and
+    /// the position of the mutation relative to the function start. So it doesn't change when
+    /// other functions are edited, added or removed.
+    pub fn stable_id(
+        &self,
+        module_name: &str,
+        function_name: &str,
+        function_start: usize,
+    ) -> String {
+        let offset = self
+            .mutation
+            .get_changed_place()
+            .start()
+            .saturating_sub(function_start)
+            .to_string();
+        stable_hash(
+            [
+                module_name,
+                function_name,
+                self.mutation.get_operator_name(),
+                self.mutation.get_original_value(),
+                self.mutation.get_new_value(),
+                offset.as_str(),
+            ]
+            .map(str::as_bytes),
+        )
+    }
 }
 
 /// Trait for mutation operators.
@@ -131,4 +163,34 @@ mod tests {
         let operator = MutationOp::new(Box::new(Binary::new(Operation::Add, loc, vec![])));
         assert_eq!(operator.get_file_id(), fid);
     }
+
+    #[test]
+    fn test_stable_id_ignores_unrelated_edits() {
+        let mutant = |source: &str, function_start: usize| {
+            let start = source.find('*').unwrap();
+            let info = MutantInfo::new(
+                source.replacen('*', "+", 1),
+                Mutation::new(
+                    crate::report::Range::new(start, start + 1),
+                    "binary_operator_replacement".to_owned(),
+                    "*".to_owned(),
+                    "+".to_owned(),
+                ),
+            );
+            (
+                info.unique_id(),
+                info.stable_id("Sum", "mul", function_start),
+            )
+        };
+
+        let source = "fun mul(x: u64, y: u64): u64 { x * y }";
+        let edited =
+            "fun add(x: u64, y: u64): u64 { x + y }\nfun mul(x: u64, y: u64): u64 { x * y }";
+        let (unique_id, stable_id) = mutant(source, 0);
+        let (edited_unique_id, edited_stable_id) = mutant(edited, edited.find("fun mul").unwrap());
+
+        assert_ne!(unique_id, edited_unique_id);
+        assert_eq!(stable_id, edited_stable_id);
+        assert_ne!(stable_id, mutant(source, 4).1);
+    }
 }
@@ -7,7 +7,7 @@
 //! This module provides functionality to filter mutation operators based on their effectiveness.
 //!
 //! It supports predefined modes (Light, Medium, Heavy) and custom operator selection,
-//! where Light is the most effective (most killed mutants) and Heavy is mutating all operators.
+//! where Light is the most effective (most killed mutants) and Heavy is mutating all ranked operators.
 //!
 //! The way that the effectiveness was calculated is by running the tool on the biggest projects
 //! in [Aptos' Move Framework](https://github.com/aptos-labs/aptos-core/tree/main/aptos-move/framework).
@@ -34,13 +34,20 @@
 //! ├──────┼─────────────────────────────┼────────┼────────┼───────────────┼───────────┤
 //! │ #7   │ binary_operator_swap        │ 271    │ 114    │ 42.07%        │ 114/271   │
 //! ╰──────┴─────────────────────────────┴────────┴────────┴───────────────┴───────────╯
+//!
+//! The return_value_replacement, assert_replacement and abort_code_replacement operators are not
+//! ranked yet, so none of the modes includes them. They are enabled only when listed explicitly
+//! in a custom operator selection.
 
+use crate::operators::abort_code::OPERATOR_NAME as ABORT_CODE_NAME;
+use crate::operators::assert::OPERATOR_NAME as ASSERT_NAME;
 use crate::operators::binary::OPERATOR_NAME as BINARY_OPERATOR_NAME;
 use crate::operators::binary_swap::OPERATOR_NAME as BINARY_SWAP_NAME;
 use crate::operators::break_continue::OPERATOR_NAME as BREAK_CONTINUE_NAME;
 use crate::operators::delete_stmt::OPERATOR_NAME as DELETE_STATEMENT_NAME;
 use crate::operators::ifelse::OPERATOR_NAME as IF_ELSE_NAME;
 use crate::operators::literal::OPERATOR_NAME as LITERAL_NAME;
+use crate::operators::return_value::OPERATOR_NAME as RETURN_VALUE_NAME;
 use crate::operators::unary::OPERATOR_NAME as UNARY_OPERATOR_NAME;
 use std::str::FromStr;
 
@@ -54,6 +61,9 @@ pub enum Operator {
     IfElseReplacement,
     LiteralReplacement,
     BinaryOperatorSwap,
+    ReturnValueReplacement,
+    AssertReplacement,
+    AbortCodeReplacement,
 }
 
 impl Operator {
@@ -66,10 +76,13 @@ impl Operator {
             Self::IfElseReplacement => IF_ELSE_NAME,
             Self::LiteralReplacement => LITERAL_NAME,
             Self::BinaryOperatorSwap => BINARY_SWAP_NAME,
+            Self::ReturnValueReplacement => RETURN_VALUE_NAME,
+            Self::AssertReplacement => ASSERT_NAME,
+            Self::AbortCodeReplacement => ABORT_CODE_NAME,
         }
     }
 
-    const fn all() -> [Operator; 7] {
+    const fn all() -> [Operator; 10] {
         [
             Operator::UnaryOperatorReplacement,
             Operator::DeleteStatement,
@@ -78,6 +91,9 @@ impl Operator {
             Operator::IfElseReplacement,
             Operator::LiteralReplacement,
             Operator::BinaryOperatorSwap,
+            Operator::ReturnValueReplacement,
+            Operator::AssertReplacement,
+            Operator::AbortCodeReplacement,
         ]
     }
 }
@@ -94,6 +110,9 @@ impl FromStr for Operator {
             IF_ELSE_NAME => Ok(Self::IfElseReplacement),
             LITERAL_NAME => Ok(Self::LiteralReplacement),
             BINARY_SWAP_NAME => Ok(Self::BinaryOperatorSwap),
+            RETURN_VALUE_NAME => Ok(Self::ReturnValueReplacement),
+            ASSERT_NAME => Ok(Self::AssertReplacement),
+            ABORT_CODE_NAME => Ok(Self::AbortCodeReplacement),
             _ => anyhow::bail!("Unknown operator: {}", s),
         }
     }
@@ -109,8 +128,11 @@ impl FromStr for Operator {
 /// - Light: binary_operator_swap, break_continue_replacement, delete_statement (3 operators)
 /// - Medium: Light + literal_replacement (4 operators)
 /// - Medium-only: literal_replacement (1 operator - only what's added in medium)
-/// - Heavy: All 7 operators
+/// - Heavy: All 7 ranked operators
 /// - Heavy-only: unary_operator_replacement, binary_operator_replacement, if_else_replacement (3 operators - only what's added in heavy)
+///
+/// The operators which are not ranked yet (return_value_replacement, assert_replacement and
+/// abort_code_replacement) are enabled only in the Custom mode.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum OperatorMode {
     /// Light mode: Operators optimized for detecting test gaps with fewest mutants.
@@ -126,7 +148,7 @@ pub enum OperatorMode {
     MediumOnly,
 
     /// Heavy mode: All available operators for maximum test gap detection.
-    /// Includes all 7 operators, default mode.
+    /// Includes all 7 ranked operators, default mode.
     #[default]
     Heavy,
 
@@ -182,9 +204,17 @@ impl OperatorMode {
     }
 
     /// Returns operators for Heavy mode.
-    /// All available operators.
+    /// All ranked operators, the newer operators have to be selected explicitly.
     fn heavy_operators() -> Vec<Operator> {
-        Operator::all().to_vec()
+        vec![
+            Operator::UnaryOperatorReplacement,
+            Operator::DeleteStatement,
+            Operator::BreakContinueReplacement,
+            Operator::BinaryOperatorReplacement,
+            Operator::IfElseReplacement,
+            Operator::LiteralReplacement,
+            Operator::BinaryOperatorSwap,
+        ]
     }
 
     /// Returns operators for Heavy-only mode.
@@ -210,6 +240,29 @@ impl OperatorMode {
         self.operators_enum().contains(&operator)
     }
 
+    /// Returns the mode restricted for a single module or function.
+    ///
+    /// # Arguments
+    ///
+    /// * `skipped` - Operators to remove from this mode.
+    /// * `only` - If given, only these operators are kept from this mode.
+    ///
+    /// # Returns
+    ///
+    /// The mode with the remaining operators, or a clone of this mode if there is nothing to restrict.
+    pub fn restrict(&self, skipped: &[Operator], only: Option<&[Operator]>) -> OperatorMode {
+        if skipped.is_empty() && only.is_none() {
+            return self.clone();
+        }
+
+        OperatorMode::Custom(
+            self.operators_enum()
+                .into_iter()
+                .filter(|op| !skipped.contains(op) && only.is_none_or(|only| only.contains(op)))
+                .collect(),
+        )
+    }
+
     /// Validates a list of operator names and returns an error if any are invalid.
     ///
     /// # Arguments
@@ -299,7 +352,7 @@ mod tests {
     #[test]
     fn test_operator_all() {
         let all = Operator::all();
-        assert_eq!(all.len(), 7);
+        assert_eq!(all.len(), 10);
     }
 
     #[test]
@@ -346,7 +399,7 @@ mod tests {
         let mode = OperatorMode::Heavy;
         let ops = mode.get_operators();
         assert_eq!(ops.len(), 7);
-        // All operators should be present
+        // All ranked operators should be present
         assert!(ops.contains(&Operator::UnaryOperatorReplacement.as_str()));
         assert!(ops.contains(&Operator::DeleteStatement.as_str()));
         assert!(ops.contains(&Operator::BreakContinueReplacement.as_str()));
@@ -356,6 +409,28 @@ mod tests {
         assert!(ops.contains(&Operator::BinaryOperatorSwap.as_str()));
     }
 
+    #[test]
+    fn test_unranked_operators_are_opt_in() {
+        let unranked = [
+            Operator::ReturnValueReplacement,
+            Operator::AssertReplacement,
+            Operator::AbortCodeReplacement,
+        ];
+        let modes = [
+            OperatorMode::Light,
+            OperatorMode::Medium,
+            OperatorMode::MediumOnly,
+            OperatorMode::Heavy,
+            OperatorMode::HeavyOnly,
+        ];
+        for mode in modes {
+            assert!(unranked.iter().all(|op| !mode.should_apply(*op)));
+        }
+
+        let mode = OperatorMode::Custom(unranked.to_vec());
+        assert!(unranked.iter().all(|op| mode.should_apply(*op)));
+    }
+
     #[test]
     fn test_custom_mode() {
         let mode = OperatorMode::Custom(vec![
@@ -379,6 +454,30 @@ mod tests {
         assert!(!mode.should_apply(Operator::BinaryOperatorReplacement));
     }
 
+    #[test]
+    fn test_restrict() {
+        let mode = OperatorMode::Medium;
+        assert_eq!(mode.restrict(&[], None), OperatorMode::Medium);
+
+        let restricted = mode.restrict(&[Operator::LiteralReplacement], None);
+        assert!(!restricted.should_apply(Operator::LiteralReplacement));
+        assert!(restricted.should_apply(Operator::DeleteStatement));
+
+        // Operators outside of the mode can't be enabled by `only`.
+        let restricted = mode.restrict(
+            &[Operator::BinaryOperatorSwap],
+            Some(&[
+                Operator::BinaryOperatorSwap,
+                Operator::DeleteStatement,
+                Operator::IfElseReplacement,
+            ]),
+        );
+        assert_eq!(
+            restricted,
+            OperatorMode::Custom(vec![Operator::DeleteStatement])
+        );
+    }
+
     #[test]
     fn test_validate_operators_valid() {
         let operators = vec![
@@ -0,0 +1,280 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    operator::{MutantInfo, MutationOperator},
+    operators::ExpLoc,
+    report::{Mutation, Range},
+};
+use codespan::FileId;
+use move_model::model::Loc;
+use std::{fmt, fmt::Debug};
+
+pub const OPERATOR_NAME: &str = "abort_code_replacement";
+
+/// Name of the macro which is expanded to the `if (cond) () else abort code` expression.
+const ASSERT_MACRO: &str = "assert";
+
+/// The maximal number of error constants, and of error categories, which replace a single abort code.
+const MAX_ALTERNATIVES: usize = 2;
+
+/// Module which wraps the abort codes with the error categories.
+const ERROR_MODULE: &str = "error::";
+
+/// Functions from the `std::error` module which wrap abort codes with error categories.
+const ERROR_CATEGORIES: &[&str] = &[
+    "invalid_argument",
+    "out_of_range",
+    "invalid_state",
+    "unauthenticated",
+    "permission_denied",
+    "not_found",
+    "aborted",
+    "already_exists",
+    "resource_exhausted",
+    "canceled",
+    "internal",
+    "not_implemented",
+    "unavailable",
+];
+
+/// Abort code replacement mutation operator.
+/// Replaces the abort codes (also the ones of the `assert!` macro calls) with other error constants
+/// from the same module or changes the error category of the abort code.
+#[derive(Debug, Clone)]
+pub struct AbortCode {
+    code: ExpLoc,
+    abort_loc: Loc,
+    error_codes: Vec<String>,
+}
+
+impl AbortCode {
+    /// Creates a new instance of the abort code mutation operator.
+    ///
+    /// The `error_codes` are the names of the error constants which can replace the abort code.
+    #[must_use]
+    pub fn new(code: ExpLoc, abort_loc: Loc, error_codes: Vec<String>) -> Self {
+        Self {
+            code,
+            abort_loc,
+            error_codes,
+        }
+    }
+}
+
+/// Splits the abort code wrapped with the error category, like `error::not_found(E_CODE)`, into
+/// the path to the category, the category and the wrapped code.
+pub(crate) fn split_error_category(code: &str) -> Option<(&str, &str, &str)> {
+    let idx = code.find(ERROR_MODULE)?;
+    if idx != 0 && !code[..idx].ends_with("::") {
+        return None;
+    }
+
+    let (path, rest) = code.split_at(idx + ERROR_MODULE.len());
+    let (category, args) = rest.split_once('(')?;
+    let inner = args.strip_suffix(')')?;
+    let category = category.trim();
+
+    ERROR_CATEGORIES
+        .contains(&category)
+        .then_some((path, category, inner.trim()))
+}
+
+/// Returns up to `MAX_ALTERNATIVES` items which follow the current one in the cyclic order.
+///
+/// Following the current item keeps the choice deterministic, while different abort codes are
+/// still replaced with different items.
+fn following<'a, T: AsRef<str>>(items: &'a [T], current: &'a str) -> impl Iterator<Item = &'a str> {
+    let start = items
+        .iter()
+        .position(|item| item.as_ref() == current)
+        .map_or(0, |idx| idx + 1);
+
+    items
+        .iter()
+        .cycle()
+        .skip(start)
+        .take(items.len())
+        .map(AsRef::<str>::as_ref)
+        .filter(move |item| *item != current)
+        .take(MAX_ALTERNATIVES)
+}
+
+/// Returns the abort codes which can replace the given one.
+///
+/// Plain abort codes are replaced with other error constants. Codes wrapped with the error
+/// category get the category changed or the wrapped error constant replaced. The number of
+/// replacements is capped, so modules with many error constants don't produce lots of similar
+/// mutants.
+fn abort_code_alternatives(code: &str, error_codes: &[String]) -> Vec<String> {
+    if let Some((path, category, inner)) = split_error_category(code) {
+        following(ERROR_CATEGORIES, category)
+            .map(|c| format!("{path}{c}({inner})"))
+            .chain(following(error_codes, inner).map(|c| format!("{path}{category}({c})")))
+            .collect()
+    } else {
+        following(error_codes, code).map(str::to_owned).collect()
+    }
+}
+
+impl MutationOperator for AbortCode {
+    fn apply(&self, source: &str) -> Vec<MutantInfo> {
+        let start = self.code.loc.span().start().to_usize();
+        let end = self.code.loc.span().end().to_usize();
+        let cur_op = &source[start..end];
+
+        // The code omitted in the "assert!" macro call is located at the macro name.
+        if cur_op == ASSERT_MACRO {
+            return vec![];
+        }
+
+        abort_code_alternatives(cur_op, &self.error_codes)
+            .into_iter()
+            .map(|op| {
+                let mut mutated_source = source.to_string();
+                mutated_source.replace_range(start..end, op.as_str());
+                MutantInfo::new(
+                    mutated_source,
+                    Mutation::new(
+                        Range::new(start, end),
+                        OPERATOR_NAME.to_string(),
+                        cur_op.to_string(),
+                        op,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn get_file_id(&self) -> FileId {
+        self.abort_loc.file_id()
+    }
+
+    fn name(&self) -> String {
+        OPERATOR_NAME.to_string()
+    }
+}
+
+impl fmt::Display for AbortCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AbortCodeOperator(location: file id: {:?}, index start: {}, index stop: {})",
+            self.code.loc.file_id(),
+            self.code.loc.span().start().to_usize(),
+            self.code.loc.span().end().to_usize()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::Files;
+    use move_model::{
+        ast::{ExpData, Value},
+        model::NodeId,
+    };
+
+    fn exp_loc(fid: FileId, start: u32, end: u32) -> ExpLoc {
+        let expr = ExpData::Value(NodeId::new(1), Value::Bool(true));
+        ExpLoc::new(
+            expr.into_exp(),
+            Loc::new(fid, codespan::Span::new(start, end)),
+        )
+    }
+
+    #[test]
+    fn test_apply_error_constant() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator = AbortCode::new(
+            exp_loc(fid, 6, 12),
+            Loc::new(fid, codespan::Span::new(0, 12)),
+            vec!["E_ZERO".to_owned(), "E_MAX".to_owned(), "E_MIN".to_owned()],
+        );
+        let source = "abort E_ZERO";
+        let expected = ["abort E_MAX", "abort E_MIN"];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_assert() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator = AbortCode::new(
+            exp_loc(fid, 15, 21),
+            Loc::new(fid, codespan::Span::new(0, 6)),
+            vec!["E_ZERO".to_owned(), "E_MAX".to_owned()],
+        );
+        let source = "assert!(x > 0, E_ZERO)";
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "assert!(x > 0, E_MAX)");
+    }
+
+    #[test]
+    fn test_apply_skips_assert_without_code() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator = AbortCode::new(
+            exp_loc(fid, 0, 6),
+            Loc::new(fid, codespan::Span::new(0, 6)),
+            vec!["E_ZERO".to_owned(), "E_MAX".to_owned()],
+        );
+        let source = "assert!(x > 0)";
+        assert!(operator.apply(source).is_empty());
+    }
+
+    #[test]
+    fn test_alternatives_are_capped() {
+        let error_codes = ["E_A", "E_B", "E_C", "E_D"].map(String::from);
+        assert_eq!(abort_code_alternatives("E_C", &error_codes), vec![
+            "E_D".to_owned(),
+            "E_A".to_owned()
+        ]);
+    }
+
+    #[test]
+    fn test_error_category_alternatives() {
+        let error_codes = vec!["E_ZERO".to_owned(), "E_MAX".to_owned()];
+        let alternatives =
+            abort_code_alternatives("std::error::invalid_argument(E_ZERO)", &error_codes);
+
+        assert_eq!(alternatives, vec![
+            "std::error::out_of_range(E_ZERO)".to_owned(),
+            "std::error::invalid_state(E_ZERO)".to_owned(),
+            "std::error::invalid_argument(E_MAX)".to_owned(),
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_wrapper_is_not_category() {
+        let error_codes = vec!["E_ZERO".to_owned()];
+        assert_eq!(
+            abort_code_alternatives("my_error::invalid_argument(E_MAX)", &error_codes),
+            vec!["E_ZERO".to_owned()]
+        );
+        assert_eq!(
+            abort_code_alternatives("error::custom(E_MAX)", &error_codes),
+            vec!["E_ZERO".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_get_file_id() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator = AbortCode::new(
+            exp_loc(fid, 0, 0),
+            Loc::new(fid, codespan::Span::new(0, 0)),
+            vec![],
+        );
+        assert_eq!(operator.get_file_id(), fid);
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    operator::{MutantInfo, MutationOperator},
+    operators::{ExpLoc, MOVE_EMPTY_STMT, MOVE_TRUE},
+    report::{Mutation, Range},
+};
+use codespan::FileId;
+use move_model::model::Loc;
+use std::{fmt, fmt::Debug};
+
+pub const OPERATOR_NAME: &str = "assert_replacement";
+
+/// Name of the macro which is expanded to the `if (cond) () else abort code` expression.
+const ASSERT_MACRO: &str = "assert";
+
+/// Assert replacement mutation operator.
+/// Deletes the `assert!` macro calls and replaces their conditions with `true`.
+///
+/// The abort codes of the assertions are replaced by the abort code replacement operator.
+#[derive(Debug, Clone)]
+pub struct AssertReplacement {
+    cond: ExpLoc,
+    abort_loc: Loc,
+    code: Option<ExpLoc>,
+    replace_cond: bool,
+}
+
+impl AssertReplacement {
+    /// Creates a new instance of the assert mutation operator.
+    ///
+    /// The `abort_loc` is the location of the abort expression generated from the macro. The
+    /// condition is replaced with `true` only if `replace_cond` is set, as the if/else replacement
+    /// operator already produces the same mutant.
+    #[must_use]
+    pub fn new(cond: ExpLoc, abort_loc: Loc, code: Option<ExpLoc>, replace_cond: bool) -> Self {
+        Self {
+            cond,
+            abort_loc,
+            code,
+            replace_cond,
+        }
+    }
+
+    /// Returns the end of the abort code if it's written explicitly inside the macro call.
+    fn explicit_code_end(&self, source: &str) -> Option<usize> {
+        let code = self.code.as_ref()?;
+        let (start, end) = (
+            code.loc.span().start().to_usize(),
+            code.loc.span().end().to_usize(),
+        );
+
+        // The code defaults to a value located at the macro name when it's omitted.
+        if start < self.cond.loc.span().end().to_usize() || &source[start..end] == ASSERT_MACRO {
+            return None;
+        }
+
+        Some(end)
+    }
+}
+
+/// Finds the index of the closing parenthesis of the macro call which follows the last argument.
+fn find_closing_paren(source: &str, last_arg_end: usize) -> Option<usize> {
+    source[last_arg_end..]
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace() && *c != ',')
+        .filter(|(_, c)| *c == ')')
+        .map(|(i, _)| last_arg_end + i)
+}
+
+impl MutationOperator for AssertReplacement {
+    fn apply(&self, source: &str) -> Vec<MutantInfo> {
+        let start = self.abort_loc.span().start().to_usize();
+        let end = self.abort_loc.span().end().to_usize();
+
+        // Ordinary if/else expressions with abort are not interesting for this operator.
+        if &source[start..end] != ASSERT_MACRO {
+            return vec![];
+        }
+
+        let cond_start = self.cond.loc.span().start().to_usize();
+        let cond_end = self.cond.loc.span().end().to_usize();
+
+        let mut mutations = vec![];
+
+        // Delete the whole assertion.
+        let last_arg_end = self.explicit_code_end(source).unwrap_or(cond_end);
+        if let Some(paren) = find_closing_paren(source, last_arg_end) {
+            mutations.push((start, paren + 1, MOVE_EMPTY_STMT));
+        }
+
+        // Make the assertion always pass.
+        if self.replace_cond && &source[cond_start..cond_end] != MOVE_TRUE {
+            mutations.push((cond_start, cond_end, MOVE_TRUE));
+        }
+
+        mutations
+            .into_iter()
+            .map(|(start, end, op)| {
+                let mut mutated_source = source.to_string();
+                mutated_source.replace_range(start..end, op);
+                MutantInfo::new(
+                    mutated_source,
+                    Mutation::new(
+                        Range::new(start, end),
+                        OPERATOR_NAME.to_string(),
+                        source[start..end].to_string(),
+                        op.to_string(),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn get_file_id(&self) -> FileId {
+        self.abort_loc.file_id()
+    }
+
+    fn name(&self) -> String {
+        OPERATOR_NAME.to_string()
+    }
+}
+
+impl fmt::Display for AssertReplacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AssertReplacementOperator(location: file id: {:?}, index start: {}, index stop: {})",
+            self.abort_loc.file_id(),
+            self.abort_loc.span().start().to_usize(),
+            self.abort_loc.span().end().to_usize()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::Files;
+    use move_model::{
+        ast::{ExpData, Value},
+        model::NodeId,
+    };
+
+    fn exp_loc(fid: FileId, start: u32, end: u32) -> ExpLoc {
+        let expr = ExpData::Value(NodeId::new(1), Value::Bool(true));
+        ExpLoc::new(
+            expr.into_exp(),
+            Loc::new(fid, codespan::Span::new(start, end)),
+        )
+    }
+
+    #[test]
+    fn test_apply_assert() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "assert!(x > 0, E_ZERO); x";
+        let operator = AssertReplacement::new(
+            exp_loc(fid, 8, 13),
+            Loc::new(fid, codespan::Span::new(0, 6)),
+            Some(exp_loc(fid, 15, 21)),
+            true,
+        );
+        let expected = ["{}; x", "assert!(true, E_ZERO); x"];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_assert_keeps_condition() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "assert!(x > 0, E_ZERO); x";
+        let operator = AssertReplacement::new(
+            exp_loc(fid, 8, 13),
+            Loc::new(fid, codespan::Span::new(0, 6)),
+            Some(exp_loc(fid, 15, 21)),
+            false,
+        );
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "{}; x");
+    }
+
+    #[test]
+    fn test_apply_assert_without_code() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "assert!(x > 0 ,\n);";
+        let operator = AssertReplacement::new(
+            exp_loc(fid, 8, 13),
+            Loc::new(fid, codespan::Span::new(0, 6)),
+            Some(exp_loc(fid, 0, 6)),
+            true,
+        );
+        let expected = ["{};", "assert!(true ,\n);"];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_skips_plain_abort() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "if (x > 0) () else abort 1";
+        let operator = AssertReplacement::new(
+            exp_loc(fid, 4, 9),
+            Loc::new(fid, codespan::Span::new(19, 26)),
+            Some(exp_loc(fid, 25, 26)),
+            true,
+        );
+        assert!(operator.apply(source).is_empty());
+    }
+
+    #[test]
+    fn test_get_file_id() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator = AssertReplacement::new(
+            exp_loc(fid, 0, 0),
+            Loc::new(fid, codespan::Span::new(0, 0)),
+            None,
+            true,
+        );
+        assert_eq!(operator.get_file_id(), fid);
+    }
+}
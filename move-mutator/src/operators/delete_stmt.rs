@@ -14,8 +14,9 @@ use std::{fmt, fmt::Debug};
 pub const OPERATOR_NAME: &str = "delete_statement";
 
 /// Statement delete operator.
-/// Deletes statements which can be potentially deleted, still allowing the code to compile
-/// properly.
+/// Deletes statements which can be potentially deleted: `move_to` and `abort` calls, calls to
+/// functions which don't return any value and assignments. The result doesn't always compile,
+/// e.g. when the deleted statement was the only use of a value without the drop ability.
 #[derive(Debug, Clone)]
 pub struct DeleteStmt {
     operation: Exp,
@@ -4,6 +4,8 @@
 
 use move_model::{ast::Exp, model::Loc};
 
+pub(crate) mod abort_code;
+pub(crate) mod assert;
 pub(crate) mod binary;
 pub(crate) mod binary_swap;
 
@@ -11,8 +13,15 @@ pub(crate) mod break_continue;
 pub(crate) mod delete_stmt;
 pub(crate) mod ifelse;
 pub(crate) mod literal;
+pub(crate) mod return_value;
+pub(crate) mod spec_condition;
+pub(crate) mod spec_old;
 pub(crate) mod unary;
 
+/// Names of the operators which mutate the specifications instead of the code.
+pub(crate) const SPEC_OPERATORS: [&str; 2] =
+    [spec_condition::OPERATOR_NAME, spec_old::OPERATOR_NAME];
+
 // Section with Move constants.
 pub(crate) const MOVE_EMPTY_STMT: &str = "{}";
 pub(crate) const MOVE_CONTINUE: &str = "continue";
@@ -24,6 +33,8 @@ pub(crate) const MOVE_MAX_U256: &str =
     "115792089237316195423570985008687907853269984665640564039457584007913129639935u256";
 pub(crate) const MOVE_MAX_INFERRED_NUM: &str =
     "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+pub(crate) const MOVE_EMPTY_VECTOR: &str = "vector[]";
+pub(crate) const MOVE_OPTION_NONE: &str = "std::option::none()";
 pub(crate) const MOVE_ADDR_ZERO: &str = "0x0";
 pub(crate) const MOVE_ADDR_MAX: &str =
     "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF";
@@ -0,0 +1,205 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    operator::{MutantInfo, MutationOperator},
+    operators::{
+        MOVE_EMPTY_VECTOR, MOVE_FALSE, MOVE_MAX_INFERRED_NUM, MOVE_MAX_U256, MOVE_OPTION_NONE,
+        MOVE_TRUE, MOVE_ZERO_U256,
+    },
+    report::{Mutation, Range},
+};
+use codespan::FileId;
+use move_model::{
+    model::Loc,
+    ty::{PrimitiveType, Type},
+};
+use std::{fmt, fmt::Debug};
+
+pub const OPERATOR_NAME: &str = "return_value_replacement";
+
+/// Return value replacement mutation operator.
+/// Replaces the value returned from the function with a default or boundary value of the same type.
+#[derive(Debug, Clone)]
+pub struct ReturnValue {
+    optype: Type,
+    is_option: bool,
+    loc: Loc,
+}
+
+impl ReturnValue {
+    /// Creates a new instance of the return value mutation operator.
+    ///
+    /// The `is_option` flag tells whether the returned type is the `std::option::Option` struct.
+    #[must_use]
+    pub fn new(optype: Type, is_option: bool, loc: Loc) -> Self {
+        Self {
+            optype,
+            is_option,
+            loc,
+        }
+    }
+}
+
+impl MutationOperator for ReturnValue {
+    fn apply(&self, source: &str) -> Vec<MutantInfo> {
+        let start = self.loc.span().start().to_usize();
+        let end = self.loc.span().end().to_usize();
+        let cur_op = &source[start..end];
+
+        // Replace the returned value with:
+        // - the minimum and maximum values for integers
+        // - true and false for bools
+        // - an empty vector for vectors
+        // - none for options
+        // Other types (references, structs, tuples...) cannot be easily constructed, so skip them.
+        let ops: Vec<String> = match &self.optype {
+            Type::Primitive(PrimitiveType::Bool) => {
+                vec![MOVE_TRUE.to_owned(), MOVE_FALSE.to_owned()]
+            },
+            Type::Primitive(PrimitiveType::U8) => vec!["0".to_owned(), u8::MAX.to_string()],
+            Type::Primitive(PrimitiveType::U16) => vec!["0".to_owned(), u16::MAX.to_string()],
+            Type::Primitive(PrimitiveType::U32) => vec!["0".to_owned(), u32::MAX.to_string()],
+            Type::Primitive(PrimitiveType::U64) => vec!["0".to_owned(), u64::MAX.to_string()],
+            Type::Primitive(PrimitiveType::U128) => vec!["0".to_owned(), u128::MAX.to_string()],
+            Type::Primitive(PrimitiveType::U256) => {
+                vec![MOVE_ZERO_U256.to_owned(), MOVE_MAX_U256.to_owned()]
+            },
+            Type::Primitive(PrimitiveType::Num) => {
+                vec!["0".to_owned(), MOVE_MAX_INFERRED_NUM.to_owned()]
+            },
+            Type::Vector(_) => vec![MOVE_EMPTY_VECTOR.to_owned()],
+            Type::Struct(..) if self.is_option => vec![MOVE_OPTION_NONE.to_owned()],
+            _ => vec![],
+        };
+
+        ops.into_iter()
+            .filter(|v| cur_op != *v)
+            .map(|op| {
+                let mut mutated_source = source.to_string();
+                mutated_source.replace_range(start..end, op.as_str());
+                MutantInfo::new(
+                    mutated_source,
+                    Mutation::new(
+                        Range::new(start, end),
+                        OPERATOR_NAME.to_string(),
+                        cur_op.to_string(),
+                        op.to_string(),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn get_file_id(&self) -> FileId {
+        self.loc.file_id()
+    }
+
+    fn name(&self) -> String {
+        OPERATOR_NAME.to_string()
+    }
+}
+
+impl fmt::Display for ReturnValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ReturnValueOperator({:?}, location: file id: {:?}, index start: {}, index stop: {})",
+            self.optype,
+            self.loc.file_id(),
+            self.loc.span().start(),
+            self.loc.span().end()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::Files;
+    use move_model::{
+        model::{ModuleId, StructId},
+        symbol::SymbolPool,
+    };
+
+    #[test]
+    fn test_apply_u64() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let loc = Loc::new(fid, codespan::Span::new(0, 5));
+
+        let operator = ReturnValue::new(Type::Primitive(PrimitiveType::U64), false, loc);
+        let source = "x + y";
+        let expected = ["0".to_owned(), u64::MAX.to_string()];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_bool_skips_same_value() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let loc = Loc::new(fid, codespan::Span::new(0, 4));
+
+        let operator = ReturnValue::new(Type::Primitive(PrimitiveType::Bool), false, loc);
+        let source = MOVE_TRUE;
+        let expected = [MOVE_FALSE];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_vector() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let loc = Loc::new(fid, codespan::Span::new(7, 8));
+
+        let operator = ReturnValue::new(
+            Type::Vector(Box::new(Type::Primitive(PrimitiveType::U8))),
+            false,
+            loc,
+        );
+        let source = "return v";
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "return vector[]");
+    }
+
+    #[test]
+    fn test_apply_struct() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let loc = Loc::new(fid, codespan::Span::new(0, 3));
+        let pool = SymbolPool::new();
+        let optype = Type::Struct(ModuleId::new(0), StructId::new(pool.make("Option")), vec![
+            Type::Primitive(PrimitiveType::U64),
+        ]);
+
+        let source = "opt";
+        let operator = ReturnValue::new(optype.clone(), true, loc.clone());
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, MOVE_OPTION_NONE);
+
+        // Other structs cannot be replaced.
+        let operator = ReturnValue::new(optype, false, loc);
+        assert!(operator.apply(source).is_empty());
+    }
+
+    #[test]
+    fn test_get_file_id() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let loc = Loc::new(fid, codespan::Span::new(0, 4));
+
+        let operator = ReturnValue::new(Type::Primitive(PrimitiveType::Bool), false, loc);
+        assert_eq!(operator.get_file_id(), fid);
+    }
+}
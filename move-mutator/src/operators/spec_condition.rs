@@ -0,0 +1,216 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    operator::{MutantInfo, MutationOperator},
+    operators::ExpLoc,
+    report::{Mutation, Range},
+};
+use codespan::FileId;
+use move_model::model::Loc;
+use std::{fmt, fmt::Debug};
+
+pub const OPERATOR_NAME: &str = "spec_condition_replacement";
+
+/// Specification condition replacement mutation operator.
+/// Negates the condition, and for the conditions which the specification relies on (e.g. `requires`,
+/// `aborts_if` or an invariant) also drops the whole condition and keeps only one side of the
+/// top-level conjunction to weaken it.
+#[derive(Debug, Clone)]
+pub struct SpecCondition {
+    loc: Loc,
+    cond: Option<ExpLoc>,
+    conjuncts: Vec<ExpLoc>,
+    droppable: bool,
+}
+
+impl SpecCondition {
+    /// Creates a new instance of the spec condition mutation operator.
+    ///
+    /// The `loc` is the location of the whole condition, while `cond` is its boolean expression,
+    /// if it has one which can be negated. The `conjuncts` are the operands of the top-level `&&`
+    /// operator in the expression.
+    ///
+    /// The condition is dropped and weakened only if it's `droppable`. Dropping or weakening
+    /// an obligation (e.g. `ensures`) never breaks the proof, so such mutants always survive.
+    #[must_use]
+    pub fn new(loc: Loc, cond: Option<ExpLoc>, conjuncts: Vec<ExpLoc>, droppable: bool) -> Self {
+        Self {
+            loc,
+            cond,
+            conjuncts,
+            droppable,
+        }
+    }
+}
+
+/// Returns the end of the condition including the semicolon which terminates it, if any.
+fn end_with_semicolon(source: &str, end: usize) -> usize {
+    source[end..]
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace())
+        .filter(|(_, c)| *c == ';')
+        .map_or(end, |(i, _)| end + i + 1)
+}
+
+impl MutationOperator for SpecCondition {
+    fn apply(&self, source: &str) -> Vec<MutantInfo> {
+        let start = self.loc.span().start().to_usize();
+        let end = self.loc.span().end().to_usize();
+
+        // Conditions always start with a keyword, anything else is not a condition written
+        // by the user.
+        if !source[start..end].starts_with(char::is_alphabetic) {
+            return vec![];
+        }
+
+        let mut mutations = vec![];
+
+        // Drop the whole condition.
+        if self.droppable {
+            mutations.push((start, end_with_semicolon(source, end), String::new()));
+        }
+
+        if let Some(cond) = &self.cond {
+            let cond_start = cond.loc.span().start().to_usize();
+            let cond_end = cond.loc.span().end().to_usize();
+            let cond_source = &source[cond_start..cond_end];
+
+            // Negate the condition.
+            mutations.push((cond_start, cond_end, format!("!({cond_source})")));
+
+            // Weaken the condition by keeping only one side of the conjunction.
+            let conjuncts = self.conjuncts.iter().filter(|_| self.droppable);
+            mutations.extend(conjuncts.map(|conjunct| {
+                let conjunct_start = conjunct.loc.span().start().to_usize();
+                let conjunct_end = conjunct.loc.span().end().to_usize();
+                (
+                    cond_start,
+                    cond_end,
+                    source[conjunct_start..conjunct_end].to_owned(),
+                )
+            }));
+        }
+
+        mutations
+            .into_iter()
+            .map(|(start, end, op)| {
+                let mut mutated_source = source.to_string();
+                mutated_source.replace_range(start..end, op.as_str());
+                MutantInfo::new(
+                    mutated_source,
+                    Mutation::new(
+                        Range::new(start, end),
+                        OPERATOR_NAME.to_string(),
+                        source[start..end].to_string(),
+                        op,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn get_file_id(&self) -> FileId {
+        self.loc.file_id()
+    }
+
+    fn name(&self) -> String {
+        OPERATOR_NAME.to_string()
+    }
+}
+
+impl fmt::Display for SpecCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SpecConditionOperator(location: file id: {:?}, index start: {}, index stop: {})",
+            self.loc.file_id(),
+            self.loc.span().start().to_usize(),
+            self.loc.span().end().to_usize()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::Files;
+    use move_model::{
+        ast::{ExpData, Value},
+        model::NodeId,
+    };
+
+    fn exp_loc(fid: FileId, start: u32, end: u32) -> ExpLoc {
+        let expr = ExpData::Value(NodeId::new(1), Value::Bool(true));
+        ExpLoc::new(
+            expr.into_exp(),
+            Loc::new(fid, codespan::Span::new(start, end)),
+        )
+    }
+
+    #[test]
+    fn test_apply_spec_condition() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "ensures a && b; ensures c;";
+        let operator = SpecCondition::new(
+            Loc::new(fid, codespan::Span::new(0, 14)),
+            Some(exp_loc(fid, 8, 14)),
+            vec![exp_loc(fid, 8, 9), exp_loc(fid, 13, 14)],
+            true,
+        );
+        let expected = [
+            " ensures c;",
+            "ensures !(a && b); ensures c;",
+            "ensures a; ensures c;",
+            "ensures b; ensures c;",
+        ];
+        let result = operator.apply(source);
+        assert_eq!(result.len(), expected.len());
+        for (i, r) in result.iter().enumerate() {
+            assert_eq!(r.mutated_source, expected[i]);
+        }
+    }
+
+    #[test]
+    fn test_apply_spec_condition_not_droppable() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "ensures a && b; ensures c;";
+        let operator = SpecCondition::new(
+            Loc::new(fid, codespan::Span::new(0, 14)),
+            Some(exp_loc(fid, 8, 14)),
+            vec![exp_loc(fid, 8, 9), exp_loc(fid, 13, 14)],
+            false,
+        );
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "ensures !(a && b); ensures c;");
+    }
+
+    #[test]
+    fn test_apply_spec_condition_without_expression() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "modifies global<T>(addr) ;";
+        let operator = SpecCondition::new(
+            Loc::new(fid, codespan::Span::new(0, 24)),
+            None,
+            vec![],
+            true,
+        );
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "");
+    }
+
+    #[test]
+    fn test_get_file_id() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let operator =
+            SpecCondition::new(Loc::new(fid, codespan::Span::new(0, 0)), None, vec![], true);
+        assert_eq!(operator.get_file_id(), fid);
+    }
+}
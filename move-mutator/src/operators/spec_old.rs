@@ -0,0 +1,102 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    operator::{MutantInfo, MutationOperator},
+    operators::ExpLoc,
+    report::{Mutation, Range},
+};
+use codespan::FileId;
+use move_model::model::Loc;
+use std::{fmt, fmt::Debug};
+
+pub const OPERATOR_NAME: &str = "spec_old_replacement";
+
+/// Spec `old` replacement mutation operator.
+/// Replaces `old(x)` with `x` in the specification conditions, so the condition refers to the
+/// value after the function call instead of the value before it.
+#[derive(Debug, Clone)]
+pub struct SpecOld {
+    loc: Loc,
+    arg: ExpLoc,
+}
+
+impl SpecOld {
+    /// Creates a new instance of the spec `old` mutation operator.
+    ///
+    /// The `loc` is the location of the whole `old(x)` call, while `arg` is its argument.
+    #[must_use]
+    pub fn new(loc: Loc, arg: ExpLoc) -> Self {
+        Self { loc, arg }
+    }
+}
+
+impl MutationOperator for SpecOld {
+    fn apply(&self, source: &str) -> Vec<MutantInfo> {
+        let start = self.loc.span().start().to_usize();
+        let end = self.loc.span().end().to_usize();
+        let arg_start = self.arg.loc.span().start().to_usize();
+        let arg_end = self.arg.loc.span().end().to_usize();
+
+        // Keep the parentheses, so the argument binds the same way as before.
+        let op = format!("({})", &source[arg_start..arg_end]);
+
+        let mut mutated_source = source.to_string();
+        mutated_source.replace_range(start..end, op.as_str());
+        vec![MutantInfo::new(
+            mutated_source,
+            Mutation::new(
+                Range::new(start, end),
+                OPERATOR_NAME.to_string(),
+                source[start..end].to_string(),
+                op,
+            ),
+        )]
+    }
+
+    fn get_file_id(&self) -> FileId {
+        self.loc.file_id()
+    }
+
+    fn name(&self) -> String {
+        OPERATOR_NAME.to_string()
+    }
+}
+
+impl fmt::Display for SpecOld {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SpecOldOperator(location: file id: {:?}, index start: {}, index stop: {})",
+            self.loc.file_id(),
+            self.loc.span().start().to_usize(),
+            self.loc.span().end().to_usize()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codespan::Files;
+    use move_model::{
+        ast::{ExpData, Value},
+        model::NodeId,
+    };
+
+    #[test]
+    fn test_apply_spec_old() {
+        let mut files = Files::new();
+        let fid = files.add("test", "test");
+        let source = "ensures x == old(x) + 1;";
+        let arg = ExpLoc::new(
+            ExpData::Value(NodeId::new(1), Value::Bool(true)).into_exp(),
+            Loc::new(fid, codespan::Span::new(17, 18)),
+        );
+        let operator = SpecOld::new(Loc::new(fid, codespan::Span::new(13, 19)), arg);
+        let result = operator.apply(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].mutated_source, "ensures x == (x) + 1;");
+    }
+}
@@ -97,7 +97,7 @@ pub(crate) fn setup_mutant_path(
 ///
 /// * `anyhow::Result<PathBuf>` - Returns the path to the output directory if successful, or an error if any error occurs.
 pub(crate) fn setup_output_dir(mutator_configuration: &Configuration) -> anyhow::Result<PathBuf> {
-    // It's safe to unwrap here as we have default value for output directory.
+    // Without the option, the default output directory is used.
     let output_dir = mutator_configuration
         .project
         .out_mutant_dir
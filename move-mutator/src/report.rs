@@ -15,6 +15,9 @@ use std::{
 pub struct Report {
     /// The vector of `ReportEntry` instances.
     mutants: Vec<MutationReport>,
+    /// The mutants with the same bytecode as the original code. Their files are not kept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    equivalent: Vec<MutationReport>,
 }
 
 impl Report {
@@ -23,6 +26,7 @@ impl Report {
     pub fn new() -> Self {
         Self {
             mutants: Vec::new(),
+            equivalent: Vec::new(),
         }
     }
 
@@ -32,6 +36,12 @@ impl Report {
         self.mutants.push(entry);
     }
 
+    /// Adds a new `MutationReport` for the mutant equivalent to the original code.
+    pub fn add_equivalent_entry(&mut self, entry: MutationReport) {
+        trace!("Adding an equivalent mutant to the report: {entry:?}");
+        self.equivalent.push(entry);
+    }
+
     /// Saves the `Report` as a JSON file.
     ///
     /// # Errors
@@ -73,6 +83,9 @@ impl Report {
             writeln!(file, "Original file: {}", entry.original_file.display())?;
             writeln!(file, "Module name: {}", entry.module_name)?;
             writeln!(file, "Function name: {}", entry.function_name)?;
+            if !entry.mutant_id.is_empty() {
+                writeln!(file, "Mutant id: {}", entry.mutant_id)?;
+            }
             writeln!(file, "Mutations:")?;
             for modification in &entry.mutations {
                 writeln!(file, "  Operator: {}", modification.operator_name)?;
@@ -84,6 +97,13 @@ impl Report {
                     modification.changed_place.start, modification.changed_place.end
                 )?;
             }
+            for modification in &entry.duplicate_mutations {
+                writeln!(
+                    file,
+                    "  Duplicated by operator: {}",
+                    modification.operator_name
+                )?;
+            }
             writeln!(file, "Diff:")?;
             writeln!(file, "{}", entry.diff)?;
             writeln!(file, "----------------------------------------")?;
@@ -100,6 +120,12 @@ impl Report {
         &self.mutants
     }
 
+    /// Returns the vector of `MutationReport` instances for the equivalent mutants.
+    #[must_use]
+    pub fn get_equivalent_mutants(&self) -> &Vec<MutationReport> {
+        &self.equivalent
+    }
+
     /// Converts the `Report` to a JSON string.
     #[cfg(test)]
     pub fn to_json(&self) -> serde_json::Result<String> {
@@ -133,6 +159,18 @@ impl Range {
         assert!(start <= end);
         Self { start, end }
     }
+
+    /// Returns the start of the range.
+    #[must_use]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the end of the range.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.end
+    }
 }
 
 /// The `Mutation` struct represents a modification that was applied to a file.
@@ -185,6 +223,12 @@ impl Mutation {
     pub fn get_operator_name(&self) -> &str {
         &self.operator_name
     }
+
+    /// Returns the location of the modification.
+    #[must_use]
+    pub fn get_changed_place(&self) -> Range {
+        self.changed_place
+    }
 }
 
 /// The `MutationReport` struct represents an entry in a report.
@@ -200,8 +244,14 @@ pub struct MutationReport {
     module_name: String,
     /// The function name that the mutation is in.
     function_name: String,
+    /// The stable identity of the mutant, which doesn't change when unrelated code is edited.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    mutant_id: String,
     /// The modifications that were applied to the file.
     mutations: Vec<Mutation>,
+    /// The modifications from other operators which produced exactly the same mutant.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    duplicate_mutations: Vec<Mutation>,
     /// The diff between the original and mutated file.
     diff: String,
 }
@@ -224,17 +274,30 @@ impl MutationReport {
             original_file: original_file.to_path_buf(),
             module_name: module_name.to_owned(),
             function_name: function_name.to_owned(),
+            mutant_id: String::new(),
             mutations: vec![],
+            duplicate_mutations: vec![],
             diff: patch.to_string(),
         }
     }
 
+    /// Sets the stable identity of the mutant.
+    pub fn set_mutant_id(&mut self, mutant_id: String) {
+        self.mutant_id = mutant_id;
+    }
+
     /// Adds a `Mutation` to the `MutationReport`.
     pub fn add_modification(&mut self, modification: Mutation) {
         trace!("Adding modification to report: {modification:?}");
         self.mutations.push(modification);
     }
 
+    /// Adds a `Mutation` from another operator which produced the same mutant.
+    pub fn add_duplicate_modification(&mut self, modification: Mutation) {
+        trace!("Adding duplicate modification to report: {modification:?}");
+        self.duplicate_mutations.push(modification);
+    }
+
     /// Return the mutant path.
     #[must_use]
     pub fn mutant_path(&self) -> &PathBuf {
@@ -259,6 +322,14 @@ impl MutationReport {
         &self.function_name
     }
 
+    /// Return the stable identity of the mutant.
+    ///
+    /// Reports generated by the older versions of the mutator don't contain it, so it's empty.
+    #[must_use]
+    pub fn get_mutant_id(&self) -> &str {
+        &self.mutant_id
+    }
+
     /// Return the diff.
     #[must_use]
     pub fn get_diff(&self) -> &str {
@@ -270,6 +341,12 @@ impl MutationReport {
     pub fn get_mutations(&self) -> &Vec<Mutation> {
         &self.mutations
     }
+
+    /// Return mutations from other operators which produced the same mutant.
+    #[must_use]
+    pub fn get_duplicate_mutations(&self) -> &Vec<Mutation> {
+        &self.duplicate_mutations
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +423,7 @@ mod tests {
             "diff\n",
         );
         report_entry.add_modification(modification);
+        report_entry.set_mutant_id("0123456789abcdef".to_owned());
         report.add_entry(report_entry);
 
         let path = Path::new("test_report.txt");
@@ -358,6 +436,7 @@ mod tests {
         assert!(contents.contains("Original file: original_file"));
         assert!(contents.contains("Module name: module"));
         assert!(contents.contains("Function name: function"));
+        assert!(contents.contains("Mutant id: 0123456789abcdef"));
         assert!(contents.contains("Mutations:"));
         assert!(contents.contains("Operator: operator"));
         assert!(contents.contains("Old value: old"));
@@ -367,6 +446,55 @@ mod tests {
         fs::remove_file(path).unwrap();
     }
 
+    #[test]
+    fn duplicate_mutations_are_serialized_only_when_present() {
+        let mut report_entry = MutationReport::new(
+            Path::new("file"),
+            Path::new("original_file"),
+            "module",
+            "function",
+            "\n",
+            "diff\n",
+        );
+        let json = serde_json::to_string(&report_entry).unwrap();
+        assert!(!json.contains("duplicate_mutations"));
+
+        // Reports without the field can still be loaded.
+        let loaded: MutationReport = serde_json::from_str(&json).unwrap();
+        assert!(loaded.get_duplicate_mutations().is_empty());
+
+        let modification = Mutation::new(
+            Range::new(0, 10),
+            "operator".to_string(),
+            "old".to_string(),
+            "new".to_string(),
+        );
+        report_entry.add_duplicate_modification(modification.clone());
+        let json = serde_json::to_string(&report_entry).unwrap();
+        let loaded: MutationReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.get_duplicate_mutations(), &vec![modification]);
+    }
+
+    #[test]
+    fn equivalent_mutants_are_kept_apart() {
+        let mut report = Report::new();
+        let entry = MutationReport::new(
+            Path::new("file"),
+            Path::new("original_file"),
+            "module",
+            "function",
+            "\n",
+            "diff\n",
+        );
+        report.add_entry(entry.clone());
+        assert!(!report.to_json().unwrap().contains("equivalent"));
+
+        report.add_equivalent_entry(entry);
+        let loaded: Report = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.get_mutants().len(), 1);
+        assert_eq!(loaded.get_equivalent_mutants().len(), 1);
+    }
+
     #[test]
     #[should_panic(expected = "No such file or directory")]
     fn fails_to_save_report_to_non_existent_directory() {
@@ -5,7 +5,11 @@
 use fs_extra::dir::CopyOptions;
 use move_mutator::cli::{CLIOptions, FunctionFilter, ModuleFilter};
 use move_package::BuildConfig;
-use std::{fs, path::PathBuf};
+use mutator_common::tmp_package_dir::strip_path_prefix;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 use tempfile::tempdir;
 
 fn clone_project(move_asset_project: &str) -> PathBuf {
@@ -27,6 +31,19 @@ fn quick_build_config() -> BuildConfig {
     }
 }
 
+fn compiles(config: &BuildConfig, package_path: &Path) -> bool {
+    let mut compilation_msg = vec![];
+    config
+        .clone()
+        .resolution_graph_for_package(package_path, &mut compilation_msg)
+        .and_then(|resolved_graph| {
+            config
+                .clone()
+                .compile_package_no_exit(resolved_graph, vec![], &mut compilation_msg)
+        })
+        .is_ok()
+}
+
 const PACKAGE_PATHS: &[&str] = &[
     "tests/move-assets/breakcontinue",
     "tests/move-assets/poor_spec",
@@ -35,8 +52,13 @@ const PACKAGE_PATHS: &[&str] = &[
     "tests/move-assets/same_names",
     "tests/move-assets/simple",
     "tests/move-assets/skip_mutation_examples",
+    "tests/move-assets/skip_mutation_directives",
+    "tests/move-assets/skip_mutation_operators",
     "tests/move-assets/check_swap_operator",
     "tests/move-assets/simple_move_2_features",
+    "tests/move-assets/equivalent_mutants",
+    "tests/move-assets/return_values",
+    "tests/move-assets/assignments",
 ];
 
 // Check if the mutator works correctly on the basic packages.
@@ -94,6 +116,209 @@ fn check_mutator_verify_mutants_correctly() {
     fs::remove_dir_all(package_path).unwrap();
 }
 
+// This test checks that the mutants which do not compile are discarded when `--verify-mutants` is used.
+#[test]
+fn check_mutator_discards_mutants_which_do_not_compile() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+    let report_path = outdir.join("report.json");
+
+    // Deleting the `move_to` call leaves the `Balance` resource without the drop ability unused,
+    // so such a mutant never compiles.
+    let is_move_to_deleted = |mutant: &move_mutator::report::MutationReport| {
+        mutant.get_mutations().iter().any(|m| {
+            m.get_operator_name() == "delete_statement"
+                && m.get_original_value().starts_with("move_to")
+        })
+    };
+
+    let options = CLIOptions {
+        mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+        mutate_functions: Some(FunctionFilter::Selected(vec!["publish_balance".into()])),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    let all_mutants = report.get_mutants().len();
+    assert!(report.get_mutants().iter().any(is_move_to_deleted));
+
+    let options = CLIOptions {
+        verify_mutants: true,
+        ..options
+    };
+
+    let result = move_mutator::run_move_mutator(options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+    assert!(report.get_mutants().len() < all_mutants);
+    assert!(!report.get_mutants().iter().any(is_move_to_deleted));
+
+    // Every reported mutant builds when it replaces the original file in the package.
+    for mutant in report.get_mutants() {
+        let mutant_package = clone_project("tests/move-assets/basic_coin");
+        let original_file = strip_path_prefix(mutant.original_file_path()).unwrap();
+        fs::copy(mutant.mutant_path(), mutant_package.join(original_file)).unwrap();
+
+        assert!(
+            compiles(&config, &mutant_package),
+            "mutant {} does not compile",
+            mutant.mutant_path().display()
+        );
+        fs::remove_dir_all(mutant_package).unwrap();
+    }
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+#[test]
+fn check_mutator_drops_equivalent_mutants() {
+    let package_path = clone_project("tests/move-assets/equivalent_mutants");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        verify_mutants: true,
+        check_equivalence: true,
+        ..Default::default()
+    };
+
+    let config = quick_build_config();
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+
+    // Replacing `4 * 1` with `4 / 1` doesn't change the folded constant.
+    let equivalent = report.get_equivalent_mutants();
+    assert!(equivalent.iter().any(
+        |mutant| mutant
+            .get_mutations()
+            .iter()
+            .any(
+                |mutation| mutation.get_operator_name() == "binary_operator_replacement"
+                    && mutation.get_new_value() == "/"
+            )
+    ));
+
+    // Equivalent mutants are reported separately, aren't tested and their files are removed.
+    let tested_ids = report
+        .get_mutants()
+        .iter()
+        .map(|mutant| mutant.get_mutant_id())
+        .collect::<Vec<_>>();
+    for mutant in report.get_mutants() {
+        assert!(mutant.mutant_path().exists());
+    }
+    for mutant in equivalent {
+        assert!(!tested_ids.contains(&mutant.get_mutant_id()));
+        assert!(!mutant.mutant_path().exists());
+    }
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+#[test]
+fn check_mutator_mutates_specs() {
+    let package_path = clone_project("tests/move-assets/simple");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        mutate_specs: true,
+        ..Default::default()
+    };
+
+    let config = quick_build_config();
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+
+    // Only the specifications are mutated and the obligations are never dropped.
+    for mutant in report.get_mutants() {
+        for mutation in mutant.get_mutations() {
+            assert!(mutation.get_operator_name().starts_with("spec_"));
+            assert!(
+                !(mutation.get_original_value().starts_with("ensures")
+                    && mutation.get_new_value().is_empty())
+            );
+        }
+    }
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+#[test]
+fn check_mutator_mutates_only_functions_with_specs() {
+    let package_path = clone_project("tests/move-assets/simple");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        only_with_specs: true,
+        ..Default::default()
+    };
+
+    let config = quick_build_config();
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+
+    // The `neg_log` function is specified, while `sum` is not.
+    assert!(report
+        .get_mutants()
+        .iter()
+        .any(|m| m.get_module_name() == "Negation" && m.get_function_name() == "neg_log"));
+    assert!(report
+        .get_mutants()
+        .iter()
+        .all(|m| m.get_module_name() != "Sum"));
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+#[test]
+fn check_mutator_skips_excluded_modules_and_functions() {
+    let package_path = clone_project("tests/move-assets/simple");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        exclude_modules: Some(ModuleFilter::Selected(vec!["Oper*".to_owned()])),
+        exclude_functions: Some(FunctionFilter::Selected(vec!["Sum::*".to_owned()])),
+        ..Default::default()
+    };
+
+    let config = quick_build_config();
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+
+    for mutant in report.get_mutants() {
+        assert_ne!(mutant.get_module_name(), "Operators");
+        assert_ne!(mutant.get_module_name(), "Sum");
+    }
+    fs::remove_dir_all(package_path).unwrap();
+}
+
 // Check if the mutator fails on non-existing input path.
 #[test]
 fn check_mutator_fails_on_non_existing_path() {
@@ -189,10 +414,10 @@ fn check_mutator_cli_filters_functions_properly() {
     let not_included = "and";
 
     let options = CLIOptions {
-        mutate_functions: FunctionFilter::Selected(vec![
+        mutate_functions: Some(FunctionFilter::Selected(vec![
             target_function_1.into(),
             target_function_2.into(),
-        ]),
+        ])),
         out_mutant_dir: Some(outdir.clone()),
         ..Default::default()
     };
@@ -242,7 +467,7 @@ fn check_mutator_swap_operator_works_correctly_for_corner_cases() {
         let outdir = package_path.join("outdir");
 
         let options = CLIOptions {
-            mutate_functions: FunctionFilter::Selected(vec![fn_name.into()]),
+            mutate_functions: Some(FunctionFilter::Selected(vec![fn_name.into()])),
             out_mutant_dir: Some(outdir.clone()),
             ..Default::default()
         };
@@ -296,8 +521,8 @@ fn check_mutator_binary_replacement_operator_works_correctly_for_corner_cases_v1
         let outdir = package_path.join("outdir");
 
         let options = CLIOptions {
-            mutate_modules: ModuleFilter::Selected(vec!["BinaryReplacement".to_owned()]),
-            mutate_functions: FunctionFilter::Selected(vec![fn_name.into()]),
+            mutate_modules: Some(ModuleFilter::Selected(vec!["BinaryReplacement".to_owned()])),
+            mutate_functions: Some(FunctionFilter::Selected(vec![fn_name.into()])),
             out_mutant_dir: Some(outdir.clone()),
             ..Default::default()
         };
@@ -367,3 +592,458 @@ fn check_mutator_uses_skip_mutation_attribute_properly() {
     }
     fs::remove_dir_all(package_path).unwrap();
 }
+
+// This test checks that the operator-specific skip and only attributes restrict the operators.
+#[test]
+fn check_mutator_uses_operator_specific_attributes_properly() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/skip_mutation_operators");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let operators_in = |function: &str| {
+        report
+            .get_mutants()
+            .iter()
+            .filter(|mutant| mutant.get_function_name() == function)
+            .flat_map(|mutant| mutant.get_mutations())
+            .map(|mutation| mutation.get_operator_name().to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    // The module attribute disables the literal replacement also for the constants.
+    assert!(operators_in("<const LIMIT>").is_empty());
+
+    let sum_operators = operators_in("sum");
+    assert!(sum_operators.contains(&"binary_operator_replacement".to_owned()));
+    assert!(!sum_operators.contains(&"binary_operator_swap".to_owned()));
+    assert!(!sum_operators.contains(&"literal_replacement".to_owned()));
+
+    let store_operators = operators_in("store");
+    assert!(!store_operators.is_empty());
+    assert!(store_operators.iter().all(|op| op == "delete_statement"));
+
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the comment directives suppress the mutations on the selected lines.
+#[test]
+fn check_mutator_uses_skip_mutation_directives_properly() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/skip_mutation_directives");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let mutations = report
+        .get_mutants()
+        .iter()
+        .flat_map(|mutant| {
+            mutant
+                .get_mutations()
+                .iter()
+                .map(move |mutation| (mutant.get_function_name(), mutation))
+        })
+        .collect::<Vec<_>>();
+
+    // `skip-next-line` and `skip-start`/`skip-end` suppress all mutations on the lines.
+    assert!(!mutations.iter().any(|(function, mutation)| {
+        *function == "sum" && mutation.get_original_value().contains('+')
+    }));
+    assert!(!mutations.iter().any(|(function, mutation)| {
+        *function == "sub_twice"
+            && ["-", "*"]
+                .iter()
+                .any(|op| mutation.get_original_value().contains(op))
+    }));
+    assert!(mutations.iter().any(|(function, mutation)| {
+        *function == "sub_twice" && mutation.get_original_value() == "/"
+    }));
+
+    // The operator list limits the directive to the given operators.
+    let limit_operators = mutations
+        .iter()
+        .filter(|(function, _)| *function == "limit")
+        .map(|(_, mutation)| mutation.get_operator_name())
+        .collect::<Vec<_>>();
+    assert!(!limit_operators.is_empty());
+    assert!(!limit_operators.contains(&"literal_replacement"));
+
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the calls to functions without return values and assignments are deleted.
+#[test]
+fn check_mutator_deletes_unit_calls_and_assignments() {
+    let config = quick_build_config();
+
+    let functions_and_deleted_stmts = [
+        ("mint", "deposit(mint_addr, Coin { value: amount })"),
+        ("deposit", "*balance_ref = balance + value"),
+    ];
+
+    for (fn_name, deleted_stmt) in functions_and_deleted_stmts {
+        let package_path = clone_project("tests/move-assets/basic_coin");
+        let outdir = package_path.join("outdir");
+
+        let options = CLIOptions {
+            mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+            mutate_functions: Some(FunctionFilter::Selected(vec![fn_name.into()])),
+            out_mutant_dir: Some(outdir.clone()),
+            ..Default::default()
+        };
+
+        let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+        assert!(result.is_ok());
+
+        let report_path = outdir.join("report.json");
+        let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+        let is_deleted = report.get_mutants().iter().any(|mutant| {
+            mutant.get_mutations().iter().any(|m| {
+                m.get_operator_name() == "delete_statement"
+                    && m.get_original_value() == deleted_stmt
+                    && m.get_new_value() == "{}"
+            })
+        });
+        assert!(is_deleted, "statement not deleted in function {fn_name}");
+        fs::remove_dir_all(package_path).unwrap();
+    }
+
+    // The call moving the `check` variable without the drop ability is kept.
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+        mutate_functions: Some(FunctionFilter::Selected(vec!["transfer".into()])),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report =
+        move_mutator::report::Report::load_from_json_file(&outdir.join("report.json")).unwrap();
+    assert!(!report.get_mutants().iter().any(|mutant| {
+        mutant
+            .get_mutations()
+            .iter()
+            .any(|m| m.get_operator_name() == "delete_statement")
+    }));
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that only the assignments to the initialized variables are deleted.
+#[test]
+fn check_mutator_keeps_initializing_assignments() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/assignments");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        operators: Some(vec!["delete_statement".to_owned()]),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report =
+        move_mutator::report::Report::load_from_json_file(&outdir.join("report.json")).unwrap();
+
+    let mut deleted = report
+        .get_mutants()
+        .iter()
+        .flat_map(|mutant| {
+            mutant
+                .get_mutations()
+                .iter()
+                .map(move |m| (mutant.get_function_name(), m.get_original_value()))
+        })
+        .collect::<Vec<_>>();
+    deleted.sort();
+
+    // Without the sole assignment in `initialize`, `y` would be used unassigned.
+    assert_eq!(deleted, vec![
+        ("reassign", "y = y + 1"),
+        ("reassign_parameter", "x = x * 2"),
+    ]);
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the assertions are deleted and made to always pass.
+#[test]
+fn check_mutator_assert_replacement_operator_works_correctly() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+        mutate_functions: Some(FunctionFilter::Selected(vec!["withdraw".into()])),
+        operators: Some(vec!["assert_replacement".to_owned()]),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let mut assert_mutations = report
+        .get_mutants()
+        .iter()
+        .flat_map(|mutant| mutant.get_mutations())
+        .filter(|m| m.get_operator_name() == "assert_replacement")
+        .map(|m| (m.get_original_value(), m.get_new_value()))
+        .collect::<Vec<_>>();
+    assert_mutations.sort();
+
+    assert_eq!(assert_mutations, vec![
+        ("assert!(balance >= amount, EINSUFFICIENT_BALANCE)", "{}"),
+        ("balance >= amount", "true"),
+    ]);
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the abort codes of the assertions are replaced with other error constants,
+// and that the operators mutating the assertions don't produce the same mutants.
+#[test]
+fn check_mutator_abort_code_replacement_operator_works_correctly() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+        mutate_functions: Some(FunctionFilter::Selected(vec!["withdraw".into()])),
+        operators: Some(vec![
+            "if_else_replacement".to_owned(),
+            "assert_replacement".to_owned(),
+            "abort_code_replacement".to_owned(),
+        ]),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    // The duplicates are not generated at all, so there is nothing to remove.
+    assert!(report
+        .get_mutants()
+        .iter()
+        .all(|mutant| mutant.get_duplicate_mutations().is_empty()));
+
+    let mut abort_code_mutations = report
+        .get_mutants()
+        .iter()
+        .flat_map(|mutant| mutant.get_mutations())
+        .filter(|m| m.get_operator_name() == "abort_code_replacement")
+        .map(|m| (m.get_original_value(), m.get_new_value()))
+        .collect::<Vec<_>>();
+    abort_code_mutations.sort();
+
+    assert_eq!(abort_code_mutations, vec![
+        ("EINSUFFICIENT_BALANCE", "EALREADY_HAS_BALANCE"),
+        ("EINSUFFICIENT_BALANCE", "ENOT_MODULE_OWNER"),
+    ]);
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the module constants are mutated and attributed to the pseudo-functions.
+#[test]
+fn check_mutator_mutates_module_constants() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        mutate_modules: Some(ModuleFilter::Selected(vec!["BasicCoin".to_owned()])),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let constant_mutations = report
+        .get_mutants()
+        .iter()
+        .filter(|mutant| mutant.get_function_name() == "<const EINSUFFICIENT_BALANCE>")
+        .flat_map(|mutant| mutant.get_mutations())
+        .collect::<Vec<_>>();
+
+    assert!(!constant_mutations.is_empty());
+    for mutation in constant_mutations {
+        assert_eq!(mutation.get_operator_name(), "literal_replacement");
+        assert_eq!(mutation.get_original_value(), "1");
+    }
+
+    // Constants don't have specifications, but they are still mutated with the module.
+    let spec_options = CLIOptions {
+        only_with_specs: true,
+        ..options.clone()
+    };
+    let result = move_mutator::run_move_mutator(spec_options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(report
+        .get_mutants()
+        .iter()
+        .any(|mutant| mutant.get_function_name() == "<const EINSUFFICIENT_BALANCE>"));
+
+    // The function filters match the constants by their pseudo-function names.
+    let exclude_options = CLIOptions {
+        exclude_functions: Some(FunctionFilter::Selected(vec![
+            "<const EINSUFFICIENT_BALANCE>".into(),
+        ])),
+        ..options.clone()
+    };
+    let result = move_mutator::run_move_mutator(exclude_options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    let functions = report
+        .get_mutants()
+        .iter()
+        .map(|mutant| mutant.get_function_name())
+        .collect::<Vec<_>>();
+    assert!(!functions.contains(&"<const EINSUFFICIENT_BALANCE>"));
+    assert!(functions.contains(&"<const EALREADY_HAS_BALANCE>"));
+    assert!(functions.contains(&"withdraw"));
+
+    let constant_options = CLIOptions {
+        mutate_functions: Some(FunctionFilter::Selected(
+            vec!["BasicCoin::<const *>".into()],
+        )),
+        ..options.clone()
+    };
+    let result = move_mutator::run_move_mutator(constant_options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(!report.get_mutants().is_empty());
+    assert!(report
+        .get_mutants()
+        .iter()
+        .all(|mutant| mutant.get_function_name().starts_with("<const")));
+
+    // Constants aren't mutated when only specific functions are selected.
+    let options = CLIOptions {
+        mutate_functions: Some(FunctionFilter::Selected(vec!["withdraw".into()])),
+        ..options
+    };
+    let result = move_mutator::run_move_mutator(options, &config, &package_path);
+    assert!(result.is_ok());
+
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+    assert!(report
+        .get_mutants()
+        .iter()
+        .all(|mutant| !mutant.get_function_name().starts_with("<const")));
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that the same mutants produced by the operators are reported once.
+#[test]
+fn check_mutator_removes_duplicate_mutants() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/basic_coin");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let mut unique_mutants = std::collections::HashSet::new();
+    for mutant in report.get_mutants() {
+        assert!(
+            unique_mutants.insert((mutant.original_file_path(), mutant.get_diff())),
+            "duplicate mutant {mutant:?}"
+        );
+    }
+
+    // The error code `1` is replaced with `0` both as the minimal and as the decremented value.
+    assert!(report.get_mutants().iter().any(|mutant| mutant
+        .get_duplicate_mutations()
+        .iter()
+        .any(|m| m.get_original_value() == "1" && m.get_new_value() == "0")));
+    fs::remove_dir_all(package_path).unwrap();
+}
+
+// This test checks that only the values of the `std::option::Option` type are replaced with `none()`.
+#[test]
+fn check_mutator_replaces_only_std_options_with_none() {
+    let config = quick_build_config();
+
+    let package_path = clone_project("tests/move-assets/return_values");
+    let outdir = package_path.join("outdir");
+
+    let options = CLIOptions {
+        operators: Some(vec!["return_value_replacement".to_owned()]),
+        out_mutant_dir: Some(outdir.clone()),
+        ..Default::default()
+    };
+
+    let result = move_mutator::run_move_mutator(options.clone(), &config, &package_path);
+    assert!(result.is_ok());
+
+    let report_path = outdir.join("report.json");
+    let report = move_mutator::report::Report::load_from_json_file(&report_path).unwrap();
+
+    let mutated_functions = report
+        .get_mutants()
+        .iter()
+        .map(|mutant| (mutant.get_module_name(), mutant.get_function_name()))
+        .collect::<Vec<_>>();
+
+    // The `Option` struct of the user module named `option` cannot be replaced.
+    assert_eq!(mutated_functions, vec![("Returns", "find")]);
+    fs::remove_dir_all(package_path).unwrap();
+}
@@ -0,0 +1,21 @@
+module TestAccount::Assignments {
+    // The only assignment initializes the variable, so it cannot be deleted.
+    public fun initialize(x: u64): u64 {
+        let y;
+        y = x + 1;
+        y
+    }
+
+    // The variable is already initialized, so the assignment can be deleted.
+    public fun reassign(x: u64): u64 {
+        let y = x;
+        y = y + 1;
+        y
+    }
+
+    // The parameters are initialized as well.
+    public fun reassign_parameter(x: u64): u64 {
+        x = x * 2;
+        x
+    }
+}
@@ -0,0 +1,6 @@
+module TestAccount::Equivalent {
+    // The constant expression is folded by the compiler, so `4 / 1` and `1 * 4` give the same bytecode.
+    public fun scale(x: u64): u64 {
+        x * (4 * 1)
+    }
+}
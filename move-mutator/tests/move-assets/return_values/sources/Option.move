@@ -0,0 +1,10 @@
+// A user module which has the same name as the `std::option` module.
+module TestAccount::option {
+    struct Option has copy, drop {
+        value: u64,
+    }
+
+    public fun wrap(value: u64): Option {
+        Option { value }
+    }
+}
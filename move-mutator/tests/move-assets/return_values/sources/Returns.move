@@ -0,0 +1,7 @@
+module TestAccount::Returns {
+    use std::option::{Self, Option};
+
+    fun find(x: u64): Option<u64> {
+        option::some(x)
+    }
+}
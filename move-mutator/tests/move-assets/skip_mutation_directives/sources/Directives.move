@@ -0,0 +1,27 @@
+module TestAccount::Directives {
+    public fun sum(x: u64, y: u64): u64 {
+        // mutation:skip-next-line
+        let sum_r = x + y;
+        sum_r
+    }
+
+    public fun sub_twice(x: u64, y: u64): u64 {
+        // mutation:skip-start
+        let sub_r = x - y;
+        let twice = sub_r * 2;
+        // mutation:skip-end
+        twice / 2
+    }
+
+    public fun limit(x: u64): u64 {
+        if (x > 100) 100 else x // mutation:skip(literal_replacement)
+    }
+
+    #[test]
+    fun directives_test() {
+        assert!(sum(2, 3) == 5, 0);
+        assert!(sub_twice(5, 3) == 2, 0);
+        assert!(limit(150) == 100, 0);
+        assert!(limit(50) == 50, 0);
+    }
+}
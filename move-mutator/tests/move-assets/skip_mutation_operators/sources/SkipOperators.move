@@ -0,0 +1,28 @@
+#[mutation::skip(literal_replacement)]
+module TestAccount::SkipOperators {
+    use std::vector;
+
+    const LIMIT: u64 = 100;
+
+    #[mutation::skip(binary_operator_swap)]
+    public fun sum(x: u64, y: u64): u64 {
+        x + y + 1
+    }
+
+    #[mutation::only(delete_statement)]
+    public fun store(v: &mut vector<u64>, x: u64) {
+        vector::push_back(v, x + LIMIT);
+    }
+
+    #[test]
+    fun sum_test() {
+        assert!(sum(2, 3) == 6, 0);
+    }
+
+    #[test]
+    fun store_test() {
+        let v = vector::empty();
+        store(&mut v, 1);
+        assert!(*vector::borrow(&v, 0) == 101, 0);
+    }
+}
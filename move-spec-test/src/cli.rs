@@ -3,8 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
-use move_mutator::cli::{FunctionFilter, ModuleFilter, PackagePathCheck};
-use std::path::PathBuf;
+use move_mutator::cli::{FunctionFilter, ModuleFilter, OperatorModeArg, PackagePathCheck};
+use mutator_common::benchmark::{default_mutant_timeout, MutantTimeout};
+use std::{path::PathBuf, time::Duration};
 
 /// Command line options for specification test tool.
 #[derive(Parser, Default, Debug, Clone)]
@@ -13,25 +14,31 @@ pub struct CLIOptions {
     #[clap(long, value_parser)]
     pub move_sources: Vec<PathBuf>,
 
-    /// Work only over specified modules.
-    #[clap(
-        long,
-        value_parser,
-        default_value = "all",
-        conflicts_with = "use_generated_mutants"
-    )]
-    pub mutate_modules: ModuleFilter,
+    /// Work only over specified modules (names or glob patterns, e.g. `pool*`).
+    ///
+    /// All modules are mutated by default.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub mutate_modules: Option<ModuleFilter>,
 
-    /// Work only over specified functions (these are not qualified functions).
-    #[clap(
-        long,
-        value_parser,
-        default_value = "all",
-        conflicts_with = "use_generated_mutants"
-    )]
-    pub mutate_functions: FunctionFilter,
+    /// Skip specified modules (names or glob patterns).
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub exclude_modules: Option<ModuleFilter>,
+
+    /// Work only over specified functions (names or glob patterns, optionally qualified with
+    /// the module, e.g. `pool::swap_*`).
+    ///
+    /// All functions are mutated by default.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub mutate_functions: Option<FunctionFilter>,
+
+    /// Skip specified functions (names or glob patterns, optionally qualified with the module).
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub exclude_functions: Option<FunctionFilter>,
 
-    /// Optional configuration file for mutator tool.
+    /// Optional configuration file for mutator tool (TOML, or JSON with the `.json` extension).
+    ///
+    /// The options given on the command line take precedence over the ones from the file. The
+    /// mutants are kept in the output directory of this tool, so `out_mutant_dir` can't be set.
     #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
     pub mutator_conf: Option<PathBuf>,
 
@@ -54,6 +61,94 @@ pub struct CLIOptions {
     /// Remove averagely given percentage of mutants. See the doc for more details.
     #[clap(long, conflicts_with = "use_generated_mutants")]
     pub downsampling_ratio_percentage: Option<usize>,
+
+    /// Mutation operator mode to balance speed and specification gap detection.
+    ///
+    /// - light: binary_operator_swap, break_continue_replacement, delete_statement
+    /// - medium: light + literal_replacement
+    /// - medium-only: literal_replacement (only what's added in medium)
+    /// - heavy (default): all 7 ranked operators
+    /// - heavy-only: unary_operator_replacement, binary_operator_replacement, if_else_replacement (only what's added in heavy)
+    #[clap(
+        long,
+        value_enum,
+        conflicts_with = "operators",
+        conflicts_with = "mutate_specs",
+        conflicts_with = "use_generated_mutants"
+    )]
+    pub mode: Option<OperatorModeArg>,
+
+    /// Custom operator selection to run mutations on (comma-separated).
+    ///
+    /// Available operators: unary_operator_replacement, delete_statement, break_continue_replacement, binary_operator_replacement, if_else_replacement, literal_replacement, binary_operator_swap, return_value_replacement, assert_replacement, abort_code_replacement
+    ///
+    /// The return_value_replacement, assert_replacement and abort_code_replacement operators are
+    /// not included in any mode, they are applied only when listed here.
+    #[clap(
+        long,
+        value_parser,
+        value_delimiter = ',',
+        conflicts_with = "mode",
+        conflicts_with = "mutate_specs",
+        conflicts_with = "use_generated_mutants"
+    )]
+    pub operators: Option<Vec<String>>,
+
+    /// Mutate only the functions which have specifications, so the prover doesn't spend time on
+    /// the code which is not specified at all.
+    #[clap(long, conflicts_with = "use_generated_mutants")]
+    pub only_with_specs: bool,
+
+    /// Compile every generated mutant and discard the ones which do not compile.
+    #[clap(long, conflicts_with = "use_generated_mutants")]
+    pub verify_mutants: bool,
+
+    /// Compile every generated mutant and drop the ones with the same bytecode as the original code.
+    #[clap(long, conflicts_with = "use_generated_mutants")]
+    pub check_equivalence: bool,
+
+    /// Mutate the specification conditions instead of the code.
+    ///
+    /// Specification mutants which still verify are reported, as the mutated conditions are likely
+    /// vacuous or redundant. Use it also when the mutants passed with `--use-generated-mutants`
+    /// were generated with `move-mutator --mutate-specs`.
+    #[clap(long)]
+    pub mutate_specs: bool,
+
+    /// Keep the generated mutants and the checkpoint file with the results in the given directory,
+    /// so the run can be continued with `--resume` if it's interrupted.
+    #[clap(
+        long,
+        value_parser,
+        conflicts_with_all = ["use_generated_mutants", "resume"]
+    )]
+    pub checkpoint_dir: Option<PathBuf>,
+
+    /// Resume an interrupted run from its checkpoint directory.
+    ///
+    /// The mutants generated by the interrupted run are reused and the ones already recorded in
+    /// its checkpoint file are not proved again.
+    #[clap(long, value_parser, conflicts_with = "use_generated_mutants")]
+    pub resume: Option<PathBuf>,
+
+    /// Stop the prover on a mutant after the given number of seconds and mark it as timed out.
+    ///
+    /// With `auto`, the limit is a multiple of the time the prover took on the original code.
+    /// Only Boogie is killed when the time runs out, the other prover phases keep running in the
+    /// background until they finish. There's no limit by default.
+    #[clap(long, value_parser)]
+    pub prover_timeout: Option<MutantTimeout>,
+}
+
+impl CLIOptions {
+    /// Returns the time limit for proving a single mutant, if any.
+    ///
+    /// The `auto` limit is derived from the `original_duration` of the prover on the original code.
+    #[must_use]
+    pub fn prover_timeout(&self, original_duration: Duration) -> Option<Duration> {
+        self.prover_timeout
+            .map(|timeout| timeout.duration(original_duration))
+    }
 }
 
 impl<'a> PackagePathCheck<'a> for CLIOptions {
@@ -67,9 +162,18 @@ impl<'a> PackagePathCheck<'a> for CLIOptions {
 pub fn create_mutator_options(options: &CLIOptions) -> move_mutator::cli::CLIOptions {
     move_mutator::cli::CLIOptions {
         move_sources: options.move_sources.clone(),
+        mutator_conf: options.mutator_conf.clone(),
         mutate_modules: options.mutate_modules.clone(),
         mutate_functions: options.mutate_functions.clone(),
+        exclude_modules: options.exclude_modules.clone(),
+        exclude_functions: options.exclude_functions.clone(),
         downsampling_ratio_percentage: options.downsampling_ratio_percentage,
+        mode: options.mode,
+        operators: options.operators.clone(),
+        only_with_specs: options.only_with_specs,
+        verify_mutants: options.verify_mutants,
+        check_equivalence: options.check_equivalence,
+        mutate_specs: options.mutate_specs,
         ..Default::default()
     }
 }
@@ -99,8 +203,8 @@ mod tests {
     fn cli_options_starts_empty() {
         let options = CLIOptions::default();
         assert!(options.move_sources.is_empty());
-        assert_eq!(ModuleFilter::All, options.mutate_modules);
-        assert_eq!(FunctionFilter::All, options.mutate_functions);
+        assert!(options.mutate_modules.is_none());
+        assert!(options.mutate_functions.is_none());
         assert!(options.mutator_conf.is_none());
         assert!(options.prover_conf.is_none());
         assert!(options.output.is_none());
@@ -111,17 +215,28 @@ mod tests {
     fn create_mutator_options_copies_fields() {
         let mut options = CLIOptions::default();
         options.move_sources.push(PathBuf::from("path/to/file"));
-        options.mutate_modules =
-            ModuleFilter::Selected(vec!["mod1".to_string(), "mod2".to_string()]);
-        options.mutate_functions =
-            FunctionFilter::Selected(vec!["func1".to_string(), "func2".to_string()]);
+        options.mutate_modules = Some(ModuleFilter::Selected(vec![
+            "mod1".to_string(),
+            "mod2".to_string(),
+        ]));
+        options.mutate_functions = Some(FunctionFilter::Selected(vec![
+            "func1".to_string(),
+            "func2".to_string(),
+        ]));
         options.mutator_conf = Some(PathBuf::from("path/to/mutator/conf"));
+        options.operators = Some(vec!["delete_statement".to_string()]);
+        options.only_with_specs = true;
+        options.exclude_functions = Some(FunctionFilter::Selected(vec!["mod1::*".to_string()]));
 
         let mutator_options = create_mutator_options(&options);
 
         assert_eq!(mutator_options.move_sources, options.move_sources);
         assert_eq!(mutator_options.mutate_modules, options.mutate_modules);
         assert_eq!(mutator_options.mutate_functions, options.mutate_functions);
+        assert_eq!(mutator_options.operators, options.operators);
+        assert_eq!(mutator_options.mutator_conf, options.mutator_conf);
+        assert!(mutator_options.only_with_specs);
+        assert_eq!(mutator_options.exclude_functions, options.exclude_functions);
     }
 
     #[test]
@@ -148,4 +263,30 @@ mod tests {
         );
         assert_eq!(prover_options.backend.z3_exe, "/path/to/z3".to_owned());
     }
+
+    #[test]
+    fn prover_timeout_is_opt_in() {
+        let original_duration = Duration::from_secs(10);
+
+        let options = CLIOptions::default();
+        assert!(options.prover_timeout(original_duration).is_none());
+
+        let options = CLIOptions {
+            prover_timeout: Some(MutantTimeout::Auto),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.prover_timeout(original_duration),
+            Some(default_mutant_timeout(original_duration))
+        );
+
+        let options = CLIOptions {
+            prover_timeout: Some(MutantTimeout::Secs(120)),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.prover_timeout(original_duration),
+            Some(Duration::from_secs(120))
+        );
+    }
 }
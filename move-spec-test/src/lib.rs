@@ -9,19 +9,24 @@ extern crate pretty_env_logger;
 #[macro_use]
 extern crate log;
 
-use crate::prover::prove;
+use crate::prover::{prove, prove_mutant};
 use anyhow::anyhow;
 use fs_extra::dir::CopyOptions;
+use move_mutator::configuration::MutatorConfigFile;
 use move_package::BuildConfig;
 use mutator_common::{
-    benchmark::{Benchmark, Benchmarks},
+    benchmark::{wait_for_abandoned_threads, Benchmark, Benchmarks},
+    checkpoint::{create_checkpoint_dir, load_checkpoint, CheckpointWriter},
+    interrupt::{install_handler, is_interrupted},
     report::{MiniReport, MutantStatus, Report},
-    tmp_package_dir::{setup_outdir_and_package_path, strip_path_prefix},
+    tmp_package_dir::{cleanup_outdir, setup_outdir_and_package_path, strip_path_prefix},
 };
 use rayon::prelude::*;
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 
 /// This function runs the specification testing, which is a combination of the mutator tool and the prover tool.
@@ -52,6 +57,19 @@ pub fn run_spec_test(
     // We need to initialize logger using try_init() as it might be already initialized in some other tool
     // (e.g. move-mutator). If we use init() instead, we will get an abort.
     let _ = pretty_env_logger::try_init();
+    install_handler()?;
+
+    // The mutants are always kept in the output directory of this tool.
+    if let Some(path) = &options.mutator_conf {
+        MutatorConfigFile::reject_out_mutant_dir(path)?;
+    }
+
+    // Results are recorded as soon as they are known, so an interrupted run can be resumed.
+    let checkpoint_dir = match (&options.resume, &options.checkpoint_dir) {
+        (Some(dir), _) => Some(dir.canonicalize()?),
+        (None, Some(dir)) => Some(create_checkpoint_dir(dir)?),
+        (None, None) => None,
+    };
 
     // Setup output dir and clone package path there.
     let (outdir, package_path) = setup_outdir_and_package_path(original_package_path)?;
@@ -73,11 +91,17 @@ pub fn run_spec_test(
     let result = prove(config, &package_path, &prover_conf, &mut error_writer);
     benchmarks.executing_original_package.stop();
 
-    if let Err(e) = result {
-        let msg = format!("Original code verification failed! Prover failed with error: {e}");
-        error!("{msg}");
-        return Err(anyhow!(msg));
-    }
+    let conditions = match result {
+        Ok(conditions) => conditions,
+        Err(e) => {
+            let msg = format!("Original code verification failed! Prover failed with error: {e}");
+            error!("{msg}");
+            return Err(anyhow!(msg));
+        },
+    };
+
+    let prover_timeout = options.prover_timeout(benchmarks.executing_original_package.elapsed);
+    info!("Using the time limit for proving a single mutant: {prover_timeout:?}");
 
     // We can skip fetching the latest deps for generating mutants and proving those mutants
     // since the original prover verification already fetched the latest dependencies.
@@ -86,9 +110,13 @@ pub fn run_spec_test(
 
     let outdir_mutant = if let Some(mutant_path) = &options.use_generated_mutants {
         mutant_path.clone()
+    } else if let Some(resume_dir) = &options.resume {
+        resume_dir.join("mutants")
     } else {
         benchmarks.mutator.start();
-        let outdir_mutant = run_mutator(options, &quick_config, &package_path, &outdir)?;
+        // The mutants are kept with the checkpoint, so the run can be resumed.
+        let mutants_dir = checkpoint_dir.as_deref().unwrap_or(&outdir);
+        let outdir_mutant = run_mutator(options, &quick_config, &package_path, mutants_dir)?;
         benchmarks.mutator.stop();
         outdir_mutant
     };
@@ -96,10 +124,40 @@ pub fn run_spec_test(
     let report =
         move_mutator::report::Report::load_from_json_file(&outdir_mutant.join("report.json"))?;
 
+    let (mut finished, checkpoint) = match &checkpoint_dir {
+        Some(dir) => {
+            let finished = load_checkpoint(dir)?;
+            let checkpoint = CheckpointWriter::open(dir)?;
+            println!(
+                "Saving results to {} (use `--resume {}` to continue an interrupted run)",
+                checkpoint.path().display(),
+                dir.display()
+            );
+            (finished, Some(checkpoint))
+        },
+        None => (HashMap::new(), None),
+    };
+
+    let mut resumed_reports = vec![];
+    let mut mutants = vec![];
+    for elem in report.get_mutants() {
+        match finished.remove(elem.mutant_path()) {
+            Some(mini_report) => resumed_reports.push(mini_report),
+            None => mutants.push(elem),
+        }
+    }
+    if !resumed_reports.is_empty() {
+        println!(
+            "Skipping {} mutants already proved in the resumed run",
+            resumed_reports.len()
+        );
+    }
+
     benchmarks.executing_tests_on_mutants.start();
     let cp_opts = CopyOptions::new().content_only(true);
-    let (proving_benchmarks, mini_reports): (Vec<Benchmark>, Vec<MiniReport>) = report
-        .get_mutants()
+    // Packages of the timed out mutants, which might still be used by the abandoned prover.
+    let abandoned_dirs = Mutex::new(Vec::new());
+    let (proving_benchmarks, mini_reports): (Vec<Benchmark>, Vec<MiniReport>) = mutants
         .into_par_iter()
         .map(|elem| {
             let mut benchmark = Benchmark::new();
@@ -115,70 +173,137 @@ pub fn run_spec_test(
             // Strip prefix to get the path relative to the package directory.
             let original_file =
                 strip_path_prefix(elem.original_file_path()).expect("invalid package path");
-            let job_outdir = outdir.join(format!("prover_{rayon_tid}"));
-
-            let _ = fs::remove_dir_all(&job_outdir);
-            fs_extra::dir::copy(&package_path, &job_outdir, &cp_opts)
-                .expect("copying directory failed");
-
-            trace!(
-                "Copying mutant file {} to the package directory {}",
-                mutant_file.display(),
-                outdir.join(&original_file).display()
-            );
-            // Should never fail, since files will always exists.
-            fs::copy(mutant_file, job_outdir.join(&original_file)).expect("copying file failed");
-
-            benchmark.start();
-            let mut error_writer = std::io::sink();
-            let result = prove(&quick_config, &job_outdir, &prover_conf, &mut error_writer);
-            benchmark.stop();
 
-            let mutant_status = if let Err(e) = result {
-                trace!("Mutant killed! Prover failed with error: {e}");
-                MutantStatus::Killed
+            let (mutant_status, killed_by) = if is_interrupted() {
+                // Don't start proving new mutants once the run is interrupted.
+                (MutantStatus::Skipped, vec![])
             } else {
-                trace!("Mutant {} hasn't been killed!", mutant_file.display());
-                MutantStatus::Alive
+                let job_outdir = outdir.join(format!("prover_{rayon_tid}"));
+
+                let _ = fs::remove_dir_all(&job_outdir);
+                fs_extra::dir::copy(&package_path, &job_outdir, &cp_opts)
+                    .expect("copying directory failed");
+
+                trace!(
+                    "Copying mutant file {} to the package directory {}",
+                    mutant_file.display(),
+                    outdir.join(&original_file).display()
+                );
+                // Should never fail, since files will always exists.
+                fs::copy(mutant_file, job_outdir.join(&original_file))
+                    .expect("copying file failed");
+
+                benchmark.start();
+                let (mutant_status, killed_by) =
+                    prove_mutant(&quick_config, &job_outdir, &prover_conf, prover_timeout);
+                benchmark.stop();
+
+                if mutant_status == MutantStatus::Timeout {
+                    // The prover might still be running in the background, so give it
+                    // the directory and let the next mutant use a fresh one.
+                    let stem = mutant_file.file_stem().unwrap_or_default();
+                    let abandoned_dir = outdir.join(format!(
+                        "prover_{rayon_tid}_timeout_{}",
+                        stem.to_string_lossy()
+                    ));
+                    match fs::rename(&job_outdir, &abandoned_dir) {
+                        Ok(()) => abandoned_dirs
+                            .lock()
+                            .expect("abandoned directories lock poisoned")
+                            .push(abandoned_dir),
+                        Err(e) => {
+                            warn!("job_{rayon_tid}: cannot move the timed out package: {e}")
+                        },
+                    }
+                }
+
+                (mutant_status, killed_by)
             };
 
+            if mutant_status == MutantStatus::Alive {
+                trace!("Mutant {} hasn't been killed!", mutant_file.display());
+            }
+
             let diff = elem.get_diff().to_owned();
 
             let mut qname = elem.get_module_name().to_owned();
             qname.push_str("::");
             qname.push_str(elem.get_function_name());
 
-            (
-                benchmark,
-                MiniReport::new(original_file.to_path_buf(), qname, mutant_status, diff),
-            )
+            let benchmark = benchmark.with_label(format!(
+                "{}::{qname} ({})",
+                original_file.display(),
+                mutant_file.display()
+            ));
+
+            let mini_report =
+                MiniReport::new(original_file.to_path_buf(), qname, mutant_status, diff)
+                    .with_killed_by(killed_by)
+                    .with_mutant_id(elem.get_mutant_id().to_owned());
+            // Skipped mutants are not recorded, so they are proved when the run is resumed.
+            if let Some(checkpoint) = checkpoint
+                .as_ref()
+                .filter(|_| mutant_status != MutantStatus::Skipped)
+            {
+                if let Err(e) = checkpoint.append(mutant_file, &mini_report) {
+                    warn!("job_{rayon_tid}: cannot save the result to the checkpoint: {e}");
+                }
+            }
+
+            (benchmark, mini_report)
         })
         .collect::<Vec<(_, _)>>()
         .into_iter()
         .unzip();
 
+    // The abandoned provers cannot be stopped, so their packages are removed once they finish.
+    wait_for_abandoned_threads();
+    for dir in abandoned_dirs
+        .into_inner()
+        .expect("abandoned directories lock poisoned")
+    {
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            warn!("Cannot remove the timed out package {}: {e}", dir.display());
+        }
+    }
+
     benchmarks.executing_tests_on_mutants.stop();
     benchmarks.mutant_results = proving_benchmarks;
 
     // Prepare a report.
     let mut test_report = Report::new(original_package_path.canonicalize()?);
-    for MiniReport {
-        original_file,
-        qname,
-        mutant_status,
-        diff,
-    } in mini_reports
-    {
-        test_report.increment_mutants_tested(&original_file, &qname);
-        if let MutantStatus::Alive = mutant_status {
-            test_report.add_mutants_alive_diff(&original_file, &qname, &diff);
-        } else {
-            test_report.increment_mutants_killed(&original_file, &qname);
-            test_report.add_mutants_killed_diff(&original_file, &qname, &diff);
+    test_report.tests = conditions.into_iter().collect();
+    // The prover reports all failing conditions.
+    test_report.killed_by_complete = true;
+    let mut surviving_spec_mutants = vec![];
+    for mini_report in resumed_reports.into_iter().chain(mini_reports) {
+        if options.mutate_specs && mini_report.mutant_status == MutantStatus::Alive {
+            surviving_spec_mutants.push(mini_report.clone());
         }
+        test_report.add_mutant_result(mini_report);
+    }
+
+    // Mutants equivalent to the original code are not tested, but they are still reported.
+    for elem in report.get_equivalent_mutants() {
+        let original_file =
+            strip_path_prefix(elem.original_file_path()).expect("invalid package path");
+        let qname = format!("{}::{}", elem.get_module_name(), elem.get_function_name());
+        test_report.add_mutant_result(
+            MiniReport::new(
+                original_file.to_path_buf(),
+                qname,
+                MutantStatus::Equivalent,
+                elem.get_diff().to_owned(),
+            )
+            .with_mutant_id(elem.get_mutant_id().to_owned()),
+        );
     }
 
     test_report.print_table();
+    if options.mutate_specs {
+        print_surviving_spec_mutants(&surviving_spec_mutants);
+    }
+    benchmarks.print_mutant_durations("Prover");
 
     benchmarks.total_tool_duration.stop();
     benchmarks.display();
@@ -189,9 +314,48 @@ pub fn run_spec_test(
         println!("Report saved to: {}", out.display());
     }
 
+    if is_interrupted() {
+        cleanup_outdir(&outdir)?;
+        let resume_hint = checkpoint_dir
+            .map(|dir| format!(" (use `--resume {}` to prove them)", dir.display()))
+            .unwrap_or_default();
+        return Err(anyhow!(
+            "Specification testing was interrupted, {} mutants were skipped{resume_hint}",
+            test_report.mutants_skipped()
+        ));
+    }
+
     Ok(())
 }
 
+/// Prints the specification mutants which still verify.
+///
+/// A specification which still proves after one of its conditions is negated is likely vacuous,
+/// while a precondition or an invariant which can be dropped or weakened is likely redundant.
+/// The obligations (e.g. `ensures`) are never dropped or weakened, as such mutants always verify.
+fn print_surviving_spec_mutants(mutants: &[MiniReport]) {
+    if mutants.is_empty() {
+        println!("\nAll specification mutants were caught by the prover");
+        return;
+    }
+
+    println!(
+        "\nSpecification mutants which still verify ({}), the mutated conditions might be vacuous or redundant:",
+        mutants.len()
+    );
+    for mutant in mutants {
+        println!("  {}: {}", mutant.original_file.display(), mutant.qname);
+        // Show only the changed lines of the patch.
+        for line in mutant.diff.lines().filter(|l| {
+            (l.starts_with('-') || l.starts_with('+'))
+                && !l.starts_with("---")
+                && !l.starts_with("+++")
+        }) {
+            println!("    {line}");
+        }
+    }
+}
+
 /// This function runs the Move Mutator tool.
 fn run_mutator(
     options: &cli::CLIOptions,
@@ -2,10 +2,42 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use move_model::metadata::{CompilerVersion, LanguageVersion};
+use move_model::{
+    ast::Spec,
+    metadata::{CompilerVersion, LanguageVersion},
+    model::{GlobalEnv, Loc},
+};
 use move_package::{BuildConfig, ModelConfig};
-use std::{path::Path, time::Instant};
-use termcolor::WriteColor;
+use mutator_common::{
+    benchmark::{run_with_timeout, TimedJob},
+    report::MutantStatus,
+};
+use std::{
+    collections::BTreeSet,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+use termcolor::{NoColor, WriteColor};
+
+/// The prover reports the verification conditions which ran out of time with this marker.
+const PROVER_TIMEOUT_MARKER: &str = "out of resources/timeout";
+/// The prover reports the aborts not covered by any `aborts_if` clause with this marker.
+const ABORT_NOT_COVERED_MARKER: &str = "abort not covered";
+
+/// The prover diagnostics start with this marker followed by the error message.
+const DIAGNOSTIC_ERROR_MARKER: &str = "error: ";
+/// The location of the diagnostic follows this marker.
+const DIAGNOSTIC_LOCATION_MARKER: &str = "┌─ ";
+/// The source code lines in the diagnostic are separated from the line numbers by this marker.
+const DIAGNOSTIC_SOURCE_MARKER: &str = "│ ";
+
+/// The first line of the multi-line source code in the diagnostic is prefixed with this marker.
+const DIAGNOSTIC_MULTILINE_MARKER: &str = "╭ ";
+
+/// The time given to the prover to stop by itself after the time limit is reached, before the
+/// mutant is marked as timed out anyway.
+const WATCHDOG_GRACE_PERIOD: Duration = Duration::from_secs(30);
 
 /// The `prove` function is responsible for proving the package.
 ///
@@ -18,16 +50,299 @@ use termcolor::WriteColor;
 ///
 /// # Returns
 ///
-/// * `anyhow::Result<()>` - The result of the proving process.
+/// * `anyhow::Result<Vec<String>>` - The specification conditions of the package.
 pub(crate) fn prove<W: WriteColor>(
     config: &BuildConfig,
     package_path: &Path,
     prover_conf: &move_prover::cli::Options,
-    mut error_writer: &mut W,
-) -> anyhow::Result<()> {
-    let mut model = config.clone().move_model_for_package(
-        package_path,
-        ModelConfig {
+    error_writer: &mut W,
+) -> anyhow::Result<Vec<String>> {
+    let mut model = build_model(config, package_path)?;
+    verify_model(&mut model, package_path, prover_conf, error_writer)?;
+    Ok(spec_conditions(&model, package_path))
+}
+
+/// The `prove_mutant` function is responsible for proving the mutated package.
+///
+/// # Arguments
+///
+/// * `config` - A `BuildConfig` representing the build configuration.
+/// * `package_path` - A `Path` to the package.
+/// * `prover_conf` - `move_prover::cli::Options` the options for the prover.
+/// * `timeout` - The time limit for the prover, if any.
+///
+/// # Returns
+///
+/// * `(MutantStatus, Vec<String>)` - The status of the mutant after running the prover on it and
+///   the specification conditions which failed to verify.
+pub(crate) fn prove_mutant(
+    config: &BuildConfig,
+    package_path: &Path,
+    prover_conf: &move_prover::cli::Options,
+    timeout: Option<Duration>,
+) -> (MutantStatus, Vec<String>) {
+    let Some(timeout) = timeout else {
+        return check_mutant(config, package_path, prover_conf);
+    };
+
+    // Boogie is killed by the prover once it exceeds the hard timeout.
+    let mut prover_conf = prover_conf.clone();
+    prover_conf.backend.hard_timeout_secs = timeout.as_secs().max(1);
+
+    // Other prover phases cannot be stopped from the outside, so the prover runs in a separate
+    // thread which might be left behind when the time runs out.
+    let thread_config = config.clone();
+    let thread_package_path = package_path.to_path_buf();
+    let job = move || {
+        let started = Instant::now();
+        match check_mutant(&thread_config, &thread_package_path, &prover_conf) {
+            // The prover fails when Boogie is killed due to the hard timeout, unless some
+            // conditions failed to verify before that.
+            (MutantStatus::KilledByTest, conditions)
+                if conditions.is_empty() && started.elapsed() >= timeout =>
+            {
+                (MutantStatus::Timeout, vec![])
+            },
+            result => result,
+        }
+    };
+    match run_with_timeout("mutant-prover", timeout + WATCHDOG_GRACE_PERIOD, job) {
+        Ok(TimedJob::Finished(result)) => result,
+        Ok(TimedJob::TimedOut(late_result)) => {
+            info!("The prover didn't finish on the mutant {package_path:?} in {timeout:?}");
+            let Some(result) = late_result else {
+                warn!("The prover thread of the timed out mutant {package_path:?} is left running");
+                return (MutantStatus::Timeout, vec![]);
+            };
+            warn!(
+                "Too many prover threads are left running, waited for the prover on the timed out \
+                 mutant {package_path:?}"
+            );
+
+            // The time limit is exceeded anyway, unless some conditions failed to verify.
+            match result {
+                (MutantStatus::KilledByTest, conditions) if !conditions.is_empty() => {
+                    (MutantStatus::KilledByTest, conditions)
+                },
+                _ => (MutantStatus::Timeout, vec![]),
+            }
+        },
+        Ok(TimedJob::Panicked) => {
+            warn!("Running the prover on the mutant {package_path:?} failed unexpectedly");
+            (MutantStatus::ToolError, vec![])
+        },
+        Err(e) => {
+            warn!("Cannot spawn a thread to run the prover on the mutant {package_path:?}: {e}");
+            (MutantStatus::ToolError, vec![])
+        },
+    }
+}
+
+/// Runs the prover on the mutated package in the current thread.
+fn check_mutant(
+    config: &BuildConfig,
+    package_path: &Path,
+    prover_conf: &move_prover::cli::Options,
+) -> (MutantStatus, Vec<String>) {
+    // No need to print anything to the screen, due to many threads, it might be messy and slow.
+    // The output is kept only to find out why the mutant was killed.
+    let mut error_writer = NoColor::new(Vec::<u8>::new());
+
+    // Some mutants might crash the prover, which shouldn't stop proving the other mutants.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        // The package is resolved before building the model, so the errors which are not caused
+        // by the mutant (e.g. unresolved dependencies or IO errors) are not mistaken for the
+        // compilation failures.
+        if let Err(e) = config
+            .clone()
+            .resolution_graph_for_package(package_path, &mut Vec::new())
+        {
+            warn!("Cannot run the prover on the mutant {package_path:?}: {e}");
+            return (MutantStatus::ToolError, vec![]);
+        }
+
+        // The compiler reports the errors in the mutated package either in the model or as
+        // the error of the model building.
+        let mut model = match build_model(config, package_path) {
+            Ok(model) if !model.has_errors() => model,
+            Ok(_) => {
+                trace!("Mutant killed! The mutated package doesn't compile");
+                return (MutantStatus::KilledByCompileError, vec![]);
+            },
+            Err(e) => {
+                trace!("Mutant killed! Model building failed with error: {e}");
+                return (MutantStatus::KilledByCompileError, vec![]);
+            },
+        };
+
+        if let Err(e) = verify_model(&mut model, package_path, prover_conf, &mut error_writer) {
+            let output = String::from_utf8_lossy(error_writer.get_ref());
+            trace!("Mutant killed! Prover failed with error: {e} and output: {output}");
+
+            // Verification conditions which ran out of time don't mean the specification caught
+            // the mutant, but any other failing condition does.
+            if only_timeouts(&output) {
+                (MutantStatus::Timeout, vec![])
+            } else {
+                (
+                    MutantStatus::KilledByTest,
+                    failed_conditions(&output, package_path),
+                )
+            }
+        } else {
+            (MutantStatus::Alive, vec![])
+        }
+    }));
+
+    result.unwrap_or_else(|_| {
+        warn!("Running the prover on the mutant {package_path:?} panicked");
+        (MutantStatus::ToolError, vec![])
+    })
+}
+
+/// Describes the specification condition at the given location in the same way for the conditions
+/// found in the model and in the prover diagnostics, e.g. `sources/Sum.move:7:9: ensures result == x + y;`.
+///
+/// The `line` and the `column` start from 1 and the `text` is the first line of the condition.
+fn describe_condition(
+    file: &Path,
+    package_path: &Path,
+    line: usize,
+    column: usize,
+    text: &str,
+) -> String {
+    // The package path might be reported in the canonical form.
+    let canonical_package_path = package_path.canonicalize().ok();
+    let relative_file = file
+        .strip_prefix(package_path)
+        .ok()
+        .or_else(|| file.strip_prefix(canonical_package_path.as_deref()?).ok())
+        .unwrap_or(file);
+    format!(
+        "{}:{line}:{column}: {}",
+        relative_file.display(),
+        text.trim()
+    )
+}
+
+/// Returns all specification conditions (e.g. `ensures`, `aborts_if` or invariants) of the target
+/// modules in the model.
+fn spec_conditions(model: &GlobalEnv, package_path: &Path) -> Vec<String> {
+    let describe = |loc: &Loc| {
+        let (file, location) = model.get_file_and_location(loc)?;
+        let text = model.get_source(loc).ok()?.lines().next()?.to_owned();
+        Some(describe_condition(
+            Path::new(&file),
+            package_path,
+            location.line.0 as usize + 1,
+            location.column.0 as usize + 1,
+            &text,
+        ))
+    };
+
+    let mut conditions = BTreeSet::new();
+    let mut add_conditions = |spec: &Spec| {
+        conditions.extend(spec.conditions.iter().filter_map(|c| describe(&c.loc)));
+    };
+    for module in model.get_modules().filter(|m| m.is_target()) {
+        add_conditions(&module.get_spec());
+        module
+            .get_structs()
+            .for_each(|s| add_conditions(&s.get_spec()));
+        module
+            .get_functions()
+            .for_each(|f| add_conditions(&f.get_spec()));
+    }
+    conditions.into_iter().collect()
+}
+
+/// Checks if all errors in the prover diagnostics are verification conditions which ran out of time.
+fn only_timeouts(output: &str) -> bool {
+    let mut errors = output
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| line.starts_with(DIAGNOSTIC_ERROR_MARKER))
+        .peekable();
+    errors.peek().is_some() && errors.all(|line| line.contains(PROVER_TIMEOUT_MARKER))
+}
+
+/// Extracts the specification conditions which failed to verify from the prover diagnostics.
+///
+/// The verification conditions which ran out of time are not reported as failed. The aborts not
+/// covered by the `aborts_if` clauses point to the whole function spec rather than to a condition,
+/// so they are reported as e.g. `sum: abort not covered`.
+fn failed_conditions(output: &str, package_path: &Path) -> Vec<String> {
+    let mut conditions = BTreeSet::new();
+    let mut lines = output.lines().map(str::trim_start);
+
+    while let Some(line) = lines.next() {
+        if !line.starts_with(DIAGNOSTIC_ERROR_MARKER) || line.contains(PROVER_TIMEOUT_MARKER) {
+            continue;
+        }
+        let abort_not_covered = line.contains(ABORT_NOT_COVERED_MARKER);
+
+        // The primary location follows the error message, e.g. `┌─ sources/Sum.move:7:9`.
+        let Some(location) = lines.find_map(|l| l.strip_prefix(DIAGNOSTIC_LOCATION_MARKER)) else {
+            break;
+        };
+        let mut parts = location.trim().rsplitn(3, ':');
+        let (Some(column), Some(line_no), Some(file)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let (Ok(column), Ok(line_no)) = (column.parse::<usize>(), line_no.parse::<usize>()) else {
+            continue;
+        };
+
+        // The source code line is shown as `7 │         ensures result == x + y;`.
+        let source_prefix = format!("{line_no} {DIAGNOSTIC_SOURCE_MARKER}");
+        let Some(source) = lines.find_map(|l| l.strip_prefix(&source_prefix).map(str::to_owned))
+        else {
+            continue;
+        };
+        // Conditions spanning multiple lines are marked with an additional gutter marker.
+        let source = source
+            .strip_prefix(DIAGNOSTIC_MULTILINE_MARKER)
+            .unwrap_or(&source);
+        let text = source
+            .chars()
+            .skip(column.saturating_sub(1))
+            .collect::<String>();
+
+        if abort_not_covered {
+            // The spec header is shown as `spec sum {`.
+            if let Some(function) = spec_function(&text) {
+                conditions.insert(format!("{function}: {ABORT_NOT_COVERED_MARKER}"));
+            }
+            continue;
+        }
+
+        conditions.insert(describe_condition(
+            Path::new(file),
+            package_path,
+            line_no,
+            column,
+            &text,
+        ));
+    }
+
+    conditions.into_iter().collect()
+}
+
+/// Returns the name of the function from the spec block header, e.g. `sum` for `spec sum {`.
+fn spec_function(header: &str) -> Option<&str> {
+    let name = header.trim_start().strip_prefix("spec ")?.trim_start();
+    let end = name
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(name.len());
+    (end > 0).then(|| &name[..end])
+}
+
+/// Builds the Move model for the package.
+fn build_model(config: &BuildConfig, package_path: &Path) -> anyhow::Result<GlobalEnv> {
+    config
+        .clone()
+        .move_model_for_package(package_path, ModelConfig {
             // Do not run prover on dependencies, but focus on the local project only.
             all_files_as_targets: false,
             target_filter: None,
@@ -39,9 +354,16 @@ pub(crate) fn prove<W: WriteColor>(
                 .compiler_config
                 .language_version
                 .unwrap_or(LanguageVersion::V1),
-        },
-    )?;
+        })
+}
 
+/// Runs the prover on the already built Move model.
+fn verify_model<W: WriteColor>(
+    model: &mut GlobalEnv,
+    package_path: &Path,
+    prover_conf: &move_prover::cli::Options,
+    mut error_writer: &mut W,
+) -> anyhow::Result<()> {
     let mut prover_conf = prover_conf.clone();
     prover_conf.output_path = package_path
         .to_path_buf()
@@ -52,5 +374,75 @@ pub(crate) fn prove<W: WriteColor>(
 
     let now = Instant::now();
 
-    move_prover::run_move_prover_with_model_v2(&mut model, &mut error_writer, prover_conf, now)
+    move_prover::run_move_prover_with_model_v2(model, &mut error_writer, prover_conf, now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIAGNOSTICS: &str = r#"
+error: post-condition does not hold
+   ┌─ /tmp/prover_0/sources/Sum.move:7:9
+   │
+ 7 │         ensures result == x + y;
+   │         ^^^^^^^^^^^^^^^^^^^^^^^^
+   │
+   =     at /tmp/prover_0/sources/Sum.move:2: sum
+
+error: abort not covered by any of the `aborts_if` clauses
+   ┌─ /tmp/prover_0/sources/Sum.move:5:5
+   │
+ 5 │ ╭     spec sum {
+ 6 │ │         aborts_if false;
+   │ ╰─────^
+"#;
+
+    #[test]
+    fn failed_conditions_are_extracted_from_diagnostics() {
+        let conditions = failed_conditions(DIAGNOSTICS, Path::new("/tmp/prover_0"));
+        assert_eq!(conditions, vec![
+            "sources/Sum.move:7:9: ensures result == x + y;".to_owned(),
+            "sum: abort not covered".to_owned(),
+        ]);
+    }
+
+    #[test]
+    fn spec_function_is_extracted_from_header() {
+        assert_eq!(spec_function("spec sum {"), Some("sum"));
+        assert_eq!(
+            spec_function("  spec  sum_all<T>(v: vector<T>) {"),
+            Some("sum_all")
+        );
+        assert_eq!(spec_function("spec {"), None);
+        assert_eq!(spec_function("ensures result == x + y;"), None);
+    }
+
+    #[test]
+    fn timed_out_conditions_are_not_failed() {
+        let timeout = "\
+error: verification out of resources/timeout (global timeout set to 40s)
+   ┌─ /tmp/prover_0/sources/Sum.move:8:9
+   │
+ 8 │         ensures result == x * y;
+   │         ^^^^^^^^^^^^^^^^^^^^^^^^
+";
+        let package_path = Path::new("/tmp/prover_0");
+        assert!(only_timeouts(timeout));
+        assert!(failed_conditions(timeout, package_path).is_empty());
+
+        // Another failing condition kills the mutant.
+        let output = format!("{timeout}\n{DIAGNOSTICS}");
+        assert!(!only_timeouts(&output));
+        assert_eq!(failed_conditions(&output, package_path).len(), 2);
+
+        assert!(!only_timeouts(DIAGNOSTICS));
+        assert!(!only_timeouts(""));
+    }
+
+    #[test]
+    fn no_conditions_without_errors() {
+        assert!(failed_conditions("", Path::new("/tmp/prover_0")).is_empty());
+        assert!(failed_conditions("warning: unused variable", Path::new("/tmp")).is_empty());
+    }
 }
@@ -3,7 +3,175 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use log::info;
-use std::time::{Duration, Instant};
+use std::{
+    io,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The minimal default time limit for processing a single mutant.
+const MIN_MUTANT_TIMEOUT: Duration = Duration::from_secs(60);
+/// How many times longer than the original package a mutant can be processed by default.
+const MUTANT_TIMEOUT_FACTOR: u32 = 5;
+/// The number of the slowest mutants shown in the duration summary.
+const SLOWEST_MUTANTS_SHOWN: usize = 5;
+
+/// The maximal number of the threads which are left running after their mutants timed out.
+///
+/// The abandoned threads keep using the CPU and slow down the next mutants, so once the limit is
+/// reached, the next timed out mutant waits for its thread to finish.
+const MAX_ABANDONED_THREADS: usize = 2;
+/// How often the abandoned threads are checked when waiting for them to finish.
+const ABANDONED_THREADS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The number of the abandoned threads which are still running.
+static ABANDONED_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The states of the job thread, shared with the caller waiting for the result.
+const JOB_RUNNING: u8 = 0;
+const JOB_FINISHED: u8 = 1;
+const JOB_ABANDONED: u8 = 2;
+
+/// Returns the default time limit for processing a single mutant.
+///
+/// The limit is derived from the `original_duration` of the same operation on the original package.
+/// Since the original package is processed on all threads and each mutant is processed on
+/// a single thread, the duration is scaled by the number of threads as well.
+pub fn default_mutant_timeout(original_duration: Duration) -> Duration {
+    let num_threads = thread::available_parallelism().map_or(1, |n| n.get());
+    original_duration
+        .saturating_mul(MUTANT_TIMEOUT_FACTOR)
+        .saturating_mul(u32::try_from(num_threads).unwrap_or(u32::MAX))
+        .max(MIN_MUTANT_TIMEOUT)
+}
+
+/// The time limit for processing a single mutant, given as `auto` or a number of seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutantTimeout {
+    /// The limit is derived from the duration on the original package, see [`default_mutant_timeout`].
+    Auto,
+    /// The limit is the given number of seconds.
+    Secs(u64),
+}
+
+impl MutantTimeout {
+    /// Returns the time limit for the `original_duration` of the same operation on the original package.
+    pub fn duration(self, original_duration: Duration) -> Duration {
+        match self {
+            MutantTimeout::Auto => default_mutant_timeout(original_duration),
+            MutantTimeout::Secs(secs) => Duration::from_secs(secs),
+        }
+    }
+}
+
+impl FromStr for MutantTimeout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(MutantTimeout::Auto),
+            _ => match s.parse::<u64>() {
+                Ok(0) | Err(_) => Err(format!(
+                    "invalid time limit `{s}`, expected `auto` or a positive number of seconds"
+                )),
+                Ok(secs) => Ok(MutantTimeout::Secs(secs)),
+            },
+        }
+    }
+}
+
+/// The outcome of a job run with [`run_with_timeout`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum TimedJob<T> {
+    /// The job finished in time.
+    Finished(T),
+    /// The job didn't finish in time. Its thread is left running, unless too many threads were
+    /// already left behind, in which case the job is awaited and its late result is given.
+    TimedOut(Option<T>),
+    /// The job panicked.
+    Panicked,
+}
+
+/// Runs the job processing a mutant in a separate thread and waits at most `timeout` for its result.
+///
+/// The job cannot be stopped from the outside, so its thread is left running when the time runs
+/// out. Call [`wait_for_abandoned_threads`] before removing anything the job might still use.
+pub fn run_with_timeout<T, F>(name: &str, timeout: Duration, job: F) -> io::Result<TimedJob<T>>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let state = Arc::new(AtomicU8::new(JOB_RUNNING));
+    let thread_state = Arc::clone(&state);
+    thread::Builder::new()
+        .name(name.to_owned())
+        .spawn(move || {
+            // The job has to be marked as finished even if it panics.
+            if let Ok(result) = panic::catch_unwind(AssertUnwindSafe(job)) {
+                // The receiver is gone if the job timed out, so the result is not needed anymore.
+                let _ = sender.send(result);
+            }
+            if thread_state.swap(JOB_FINISHED, Ordering::SeqCst) == JOB_ABANDONED {
+                ABANDONED_THREADS.fetch_sub(1, Ordering::SeqCst);
+            }
+        })?;
+
+    Ok(match receiver.recv_timeout(timeout) {
+        Ok(result) => TimedJob::Finished(result),
+        Err(RecvTimeoutError::Timeout) => TimedJob::TimedOut(abandon_job(&state, &receiver)),
+        Err(RecvTimeoutError::Disconnected) => TimedJob::Panicked,
+    })
+}
+
+/// Leaves the thread of the timed out job running, unless too many threads are already left
+/// behind, in which case it waits for the job to finish and returns its result.
+fn abandon_job<T>(state: &AtomicU8, receiver: &mpsc::Receiver<T>) -> Option<T> {
+    let reserved = ABANDONED_THREADS
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+            (count < MAX_ABANDONED_THREADS).then_some(count + 1)
+        })
+        .is_ok();
+
+    if reserved {
+        if state
+            .compare_exchange(
+                JOB_RUNNING,
+                JOB_ABANDONED,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+        {
+            return None;
+        }
+        // The job has just finished.
+        ABANDONED_THREADS.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    receiver.recv().ok()
+}
+
+/// Waits until all threads left running after their mutants timed out are finished, so the
+/// packages of the mutants can be removed safely.
+pub fn wait_for_abandoned_threads() {
+    let running = ABANDONED_THREADS.load(Ordering::SeqCst);
+    if running == 0 {
+        return;
+    }
+
+    info!("Waiting for {running} threads left running on the timed out mutants");
+    while ABANDONED_THREADS.load(Ordering::SeqCst) > 0 {
+        thread::sleep(ABANDONED_THREADS_POLL_INTERVAL);
+    }
+}
 
 /// A benchmark for a specific operation.
 #[derive(Debug, Clone)]
@@ -12,6 +180,8 @@ pub struct Benchmark {
     pub start_time: Instant,
     /// Duration of the operation.
     pub elapsed: Duration,
+    /// What the benchmark measures, e.g. the mutant name.
+    pub label: String,
 }
 
 impl Default for Benchmark {
@@ -26,9 +196,16 @@ impl Benchmark {
         Self {
             start_time: Instant::now(),
             elapsed: Duration::new(0, 0),
+            label: String::new(),
         }
     }
 
+    /// Sets the label describing what the benchmark measures.
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = label;
+        self
+    }
+
     /// Starts the benchmark.
     pub fn start(&mut self) {
         self.start_time = Instant::now();
@@ -109,6 +286,40 @@ impl Benchmarks {
             self.total_tool_duration.elapsed.as_millis()
         );
     }
+
+    /// Prints the summary of the durations for the mutants to stdout.
+    ///
+    /// The `operation` describes what was done on each mutant, e.g. "Prover".
+    pub fn print_mutant_durations(&self, operation: &str) {
+        // Mutants which weren't processed at all (e.g. skipped ones) are not interesting.
+        let mut results = self
+            .mutant_results
+            .iter()
+            .filter(|b| !b.elapsed.is_zero())
+            .collect::<Vec<_>>();
+        if results.is_empty() {
+            return;
+        }
+        results.sort_by(|a, b| b.elapsed.cmp(&a.elapsed));
+
+        let total = results.iter().map(|b| b.elapsed).sum::<Duration>();
+        println!(
+            "{operation} duration per mutant: min {:.2}s, max {:.2}s, average {:.2}s, median {:.2}s",
+            results[results.len() - 1].elapsed.as_secs_f64(),
+            results[0].elapsed.as_secs_f64(),
+            total.as_secs_f64() / results.len() as f64,
+            results[results.len() / 2].elapsed.as_secs_f64(),
+        );
+        println!("Slowest mutants:");
+        for result in results.iter().take(SLOWEST_MUTANTS_SHOWN) {
+            println!(
+                "    {:>8.2}s {}",
+                result.elapsed.as_secs_f64(),
+                result.label
+            );
+        }
+        println!(); // Empty line before the end
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +327,54 @@ mod tests {
     use super::*;
     use std::{thread, time::Duration};
 
+    #[test]
+    fn too_many_abandoned_threads_are_waited_for() {
+        let long_timeout = Duration::from_secs(60);
+        assert_eq!(
+            run_with_timeout("job", long_timeout, || 1).unwrap(),
+            TimedJob::Finished(1)
+        );
+        assert_eq!(
+            run_with_timeout("job", long_timeout, || -> u8 { panic!("job failed") }).unwrap(),
+            TimedJob::Panicked
+        );
+
+        let timeout = Duration::from_millis(10);
+
+        // These jobs keep running in the background until they are released.
+        let releases: Vec<_> = (0..MAX_ABANDONED_THREADS)
+            .map(|_| {
+                let (release, released) = mpsc::channel::<()>();
+                let job = move || released.recv().is_ok();
+                assert_eq!(
+                    run_with_timeout("job", timeout, job).unwrap(),
+                    TimedJob::TimedOut(None)
+                );
+                release
+            })
+            .collect();
+        assert_eq!(
+            ABANDONED_THREADS.load(Ordering::SeqCst),
+            MAX_ABANDONED_THREADS
+        );
+
+        // The limit is reached, so the next job is awaited.
+        let job = move || {
+            thread::sleep(Duration::from_millis(100));
+            2
+        };
+        assert_eq!(
+            run_with_timeout("job", timeout, job).unwrap(),
+            TimedJob::TimedOut(Some(2))
+        );
+
+        for release in releases {
+            release.send(()).unwrap();
+        }
+        wait_for_abandoned_threads();
+        assert_eq!(ABANDONED_THREADS.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn benchmark_records_correct_elapsed_time() {
         let mut benchmark = Benchmark::new();
@@ -178,4 +437,29 @@ mod tests {
         assert!(benchmarks.mutator.elapsed >= Duration::from_millis(TEN));
         assert!(benchmarks.executing_tests_on_mutants.elapsed >= Duration::from_millis(TEN));
     }
+
+    #[test]
+    fn default_mutant_timeout_has_lower_bound() {
+        assert_eq!(default_mutant_timeout(Duration::ZERO), MIN_MUTANT_TIMEOUT);
+
+        let original_duration = Duration::from_secs(1000);
+        assert!(default_mutant_timeout(original_duration) >= original_duration);
+    }
+
+    #[test]
+    fn mutant_timeout_is_parsed() {
+        let original_duration = Duration::from_secs(1000);
+
+        let timeout: MutantTimeout = "auto".parse().unwrap();
+        assert_eq!(
+            timeout.duration(original_duration),
+            default_mutant_timeout(original_duration)
+        );
+
+        let timeout: MutantTimeout = "10".parse().unwrap();
+        assert_eq!(timeout.duration(original_duration), Duration::from_secs(10));
+
+        assert!("0".parse::<MutantTimeout>().is_err());
+        assert!("10s".parse::<MutantTimeout>().is_err());
+    }
 }
@@ -0,0 +1,184 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::report::MiniReport;
+use anyhow::Result;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// The name of the checkpoint file inside the output directory.
+pub const CHECKPOINT_FILE: &str = "checkpoint.jsonl";
+
+/// A single line in the checkpoint file.
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckpointRecord {
+    /// The path to the mutant file, as stored in the mutator report.
+    mutant: PathBuf,
+    /// The result of testing the mutant.
+    #[serde(flatten)]
+    result: MiniReport,
+}
+
+/// Loads the results of the mutants already recorded in the checkpoint file in the given directory.
+///
+/// The results are keyed by the path to the mutant file. If the file doesn't exist, no results are
+/// returned. Lines which cannot be parsed (e.g. the last line written during a crash) are skipped.
+pub fn load_checkpoint(dir: &Path) -> Result<HashMap<PathBuf, MiniReport>> {
+    let path = dir.join(CHECKPOINT_FILE);
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    Ok(fs::read_to_string(&path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(
+            |line| match serde_json::from_str::<CheckpointRecord>(line) {
+                Ok(record) => Some((record.mutant, record.result)),
+                Err(e) => {
+                    warn!(
+                        "skipping invalid checkpoint record in {}: {e}",
+                        path.display()
+                    );
+                    None
+                },
+            },
+        )
+        .collect())
+}
+
+/// Creates the checkpoint directory of a new run and returns its canonical path.
+///
+/// Fails if the directory already contains the checkpoint of another run, which should be resumed
+/// instead.
+pub fn create_checkpoint_dir(dir: &Path) -> Result<PathBuf> {
+    if dir.join(CHECKPOINT_FILE).exists() {
+        anyhow::bail!(
+            "{} already contains a checkpoint, use `--resume` to continue that run or choose another directory",
+            dir.display()
+        );
+    }
+
+    fs::create_dir_all(dir)?;
+    Ok(dir.canonicalize()?)
+}
+
+/// Appends the mutant results to the checkpoint file as soon as they are known.
+///
+/// It can be shared between threads.
+#[derive(Debug)]
+pub struct CheckpointWriter {
+    /// The path to the checkpoint file.
+    path: PathBuf,
+    /// The opened checkpoint file.
+    file: Mutex<File>,
+}
+
+impl CheckpointWriter {
+    /// Opens the checkpoint file in the given directory for appending, creating it if needed.
+    pub fn open(dir: &Path) -> Result<Self> {
+        let path = dir.join(CHECKPOINT_FILE);
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        // Terminate the record which might have been cut off when the previous run crashed.
+        let content = fs::read(&path)?;
+        if content.last().is_some_and(|c| *c != b'\n') {
+            file.write_all(b"\n")?;
+        }
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Returns the path to the checkpoint file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records the result of testing the given mutant.
+    pub fn append(&self, mutant: &Path, result: &MiniReport) -> Result<()> {
+        let record = CheckpointRecord {
+            mutant: mutant.to_path_buf(),
+            result: result.clone(),
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+
+        // The whole line is written at once, so the records from different threads don't mix.
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| anyhow::Error::msg("checkpoint file lock is poisoned"))?;
+        file.write_all(&line)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::MutantStatus;
+    use tempfile::tempdir;
+
+    #[test]
+    fn recorded_results_are_loaded() {
+        let dir = tempdir().unwrap();
+        assert!(load_checkpoint(dir.path()).unwrap().is_empty());
+
+        let writer = CheckpointWriter::open(dir.path()).unwrap();
+        let result = MiniReport::new(
+            PathBuf::from("sources/Sum.move"),
+            "Sum::sum".to_owned(),
+            MutantStatus::KilledByTest,
+            "diff".to_owned(),
+        )
+        .with_killed_by(vec!["0x1::Sum::sum_test".to_owned()]);
+        writer
+            .append(Path::new("mutants/Sum_0.move"), &result)
+            .unwrap();
+
+        // Simulate a crash in the middle of writing a record.
+        fs::OpenOptions::new()
+            .append(true)
+            .open(writer.path())
+            .unwrap()
+            .write_all(b"{\"mutant\": \"mutants/Su")
+            .unwrap();
+
+        // The results recorded after resuming are not lost.
+        let writer = CheckpointWriter::open(dir.path()).unwrap();
+        writer
+            .append(Path::new("mutants/Sum_1.move"), &result)
+            .unwrap();
+
+        let loaded = load_checkpoint(dir.path()).unwrap();
+        assert_eq!(loaded.len(), 2);
+        let loaded = &loaded[Path::new("mutants/Sum_0.move")];
+        assert_eq!(loaded.mutant_status, MutantStatus::KilledByTest);
+        assert_eq!(loaded.killed_by, result.killed_by);
+    }
+
+    #[test]
+    fn checkpoint_dir_of_another_run_is_not_reused() {
+        let dir = tempdir().unwrap();
+        let checkpoint_dir = dir.path().join("checkpoint");
+
+        let created = create_checkpoint_dir(&checkpoint_dir).unwrap();
+        assert!(created.is_absolute());
+        assert!(created.is_dir());
+
+        CheckpointWriter::open(&created).unwrap();
+        assert!(create_checkpoint_dir(&checkpoint_dir).is_err());
+    }
+}
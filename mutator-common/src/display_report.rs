@@ -3,7 +3,11 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use super::report::{MutantStats, Report};
+use super::{
+    filter::{FunctionFilter, ModuleFilter},
+    report::{MutantStats, Report},
+    triage::{apply_triage_file, Triage},
+};
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use diffy::{Line, Patch, PatchFormatter};
@@ -30,25 +34,50 @@ pub enum DisplayReportCmd {
 
     /// Display report in the coverage format.
     Coverage {
-        /// Include specified modules in the report.
+        /// Include specified modules in the report (names or glob patterns).
         #[clap(long, value_parser, default_value = "all")]
         modules: ModuleFilter,
+
+        /// Exclude specified modules from the report (names or glob patterns).
+        #[clap(long, value_parser)]
+        exclude_modules: Option<ModuleFilter>,
     },
 
     /// Display mutants.
     Mutants {
-        /// Include specified modules in the report.
+        /// Include specified modules in the report (names or glob patterns).
         #[clap(long, value_parser, default_value = "all")]
         modules: ModuleFilter,
 
-        /// Include specified functions in the output.
+        /// Exclude specified modules from the report (names or glob patterns).
+        #[clap(long, value_parser)]
+        exclude_modules: Option<ModuleFilter>,
+
+        /// Include specified functions in the output (names or glob patterns, optionally
+        /// qualified with the module, e.g. `pool::swap_*`).
         #[clap(long, value_parser, default_value = "all")]
         functions: FunctionFilter,
 
+        /// Exclude specified functions from the output (names or glob patterns, optionally
+        /// qualified with the module).
+        #[clap(long, value_parser)]
+        exclude_functions: Option<FunctionFilter>,
+
         /// Specify which mutants to print.
         #[clap(long, default_value = "alive")]
         mutants: MutantFilter,
     },
+
+    /// Display tests that kill no mutants, redundant tests and a minimal test subset.
+    Tests {
+        /// Include specified modules in the report (names or glob patterns).
+        #[clap(long, value_parser, default_value = "all")]
+        modules: ModuleFilter,
+
+        /// Exclude specified modules from the report (names or glob patterns).
+        #[clap(long, value_parser)]
+        exclude_modules: Option<ModuleFilter>,
+    },
 }
 
 /// Display the report in a more readable format.
@@ -58,6 +87,11 @@ pub struct DisplayReportOptions {
     #[clap(global = true, long, default_value = "report.txt")]
     pub path_to_report: PathBuf,
 
+    /// Triage file with the reviewed surviving mutants, which are excluded from the output.
+    /// The default file is "mutation-triage.toml" in the package directory, if it exists.
+    #[clap(global = true, long)]
+    pub triage: Option<PathBuf>,
+
     /// Display report subcommands.
     #[clap(subcommand)]
     pub cmds: DisplayReportCmd,
@@ -67,17 +101,38 @@ impl DisplayReportOptions {
     /// Execute the command.
     pub fn execute(&self) -> Result<()> {
         let path_to_report = &self.path_to_report;
+        let triage = self.triage.as_deref();
 
         match &self.cmds {
-            DisplayReportCmd::Summary => display_summary(path_to_report),
-            DisplayReportCmd::Coverage { modules } => {
-                display_coverage_on_screen(path_to_report, modules)
-            },
+            DisplayReportCmd::Summary => display_summary(path_to_report, triage),
+            DisplayReportCmd::Coverage {
+                modules,
+                exclude_modules,
+            } => display_coverage_on_screen(
+                path_to_report,
+                modules,
+                exclude_modules.as_ref(),
+                triage,
+            ),
             DisplayReportCmd::Mutants {
                 modules,
+                exclude_modules,
+                functions,
+                exclude_functions,
+                mutants,
+            } => display_mutants_on_screen(
+                path_to_report,
+                modules,
+                exclude_modules.as_ref(),
                 functions,
+                exclude_functions.as_ref(),
                 mutants,
-            } => display_mutants_on_screen(path_to_report, modules, functions, mutants),
+                triage,
+            ),
+            DisplayReportCmd::Tests {
+                modules,
+                exclude_modules,
+            } => display_tests_on_screen(path_to_report, modules, exclude_modules.as_ref(), triage),
         }
     }
 }
@@ -88,6 +143,7 @@ pub enum MutantFilter {
     #[default]
     Alive,
     Killed,
+    Triaged,
     All,
 }
 
@@ -101,6 +157,11 @@ impl MutantFilter {
     fn contains_alive(&self) -> bool {
         *self == Self::All || *self == Self::Alive
     }
+
+    /// Check whether the filter allows alive mutants excluded by the triage file.
+    fn contains_triaged(&self) -> bool {
+        *self == Self::All || *self == Self::Triaged
+    }
 }
 
 impl FromStr for MutantFilter {
@@ -110,73 +171,44 @@ impl FromStr for MutantFilter {
         match s {
             "alive" => Ok(MutantFilter::Alive),
             "killed" => Ok(MutantFilter::Killed),
+            "triaged" => Ok(MutantFilter::Triaged),
             "all" => Ok(MutantFilter::All),
             _ => Err("Invalid mutant option. Allowed only: ".to_owned()),
         }
     }
 }
 
-/// Filter for functions to include in the report.
-#[derive(Default, Debug, Clone, PartialEq)]
-pub enum FunctionFilter {
-    #[default]
-    All,
-    Selected(Vec<String>),
-}
-
-impl FromStr for FunctionFilter {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "all" => Ok(FunctionFilter::All),
-            _ => Ok(FunctionFilter::Selected(
-                s.split(&[';', '-', ',']).map(String::from).collect(),
-            )),
-        }
-    }
+/// Loads the report and excludes the mutants listed in the triage file from it.
+fn load_report(
+    path_to_report: &Path,
+    triage_file: Option<&Path>,
+) -> Result<(Report, Option<Triage>)> {
+    let mut report = Report::load_from_json_file(path_to_report)?;
+    let triage = apply_triage_file(&mut report, triage_file)?;
+    Ok((report, triage))
 }
 
-/// Filter for modules to include in the report.
-#[derive(Default, Debug, Clone, PartialEq)]
-pub enum ModuleFilter {
-    #[default]
-    All,
-    Selected(Vec<String>),
-}
-
-impl FromStr for ModuleFilter {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "all" => Ok(ModuleFilter::All),
-            _ => Ok(ModuleFilter::Selected(
-                s.split(&[';', '-', ',']).map(String::from).collect(),
-            )),
-        }
+/// Returns the files containing the modules selected by the `modules` filter and not excluded
+/// by the `exclude_modules` one.
+fn get_all_files_containing_the_modules(
+    report: &Report,
+    modules: &ModuleFilter,
+    exclude_modules: Option<&ModuleFilter>,
+) -> BTreeSet<PathBuf> {
+    if *modules == ModuleFilter::All && exclude_modules.is_none() {
+        return report.entries().keys().cloned().collect();
     }
-}
 
-impl ModuleFilter {
-    fn get_all_files_containing_the_modules(&self, report: &Report) -> BTreeSet<PathBuf> {
-        match *self {
-            Self::All => report.entries().keys().cloned().collect(),
-            Self::Selected(ref modules) => {
-                let mut files_to_print = BTreeSet::<PathBuf>::new();
-
-                for module in modules {
-                    for (file, mutants) in report.entries() {
-                        if mutants.iter().any(|m| &m.get_module_name() == module) {
-                            files_to_print.insert(file.clone());
-                            break;
-                        }
-                    }
-                }
-                files_to_print
-            },
-        }
-    }
+    report
+        .entries()
+        .iter()
+        .filter(|(_, mutants)| {
+            mutants
+                .iter()
+                .any(|m| modules.selects(exclude_modules, &m.get_module_name()))
+        })
+        .map(|(file, _)| file.clone())
+        .collect()
 }
 
 /// Line stats for mutations.
@@ -225,9 +257,11 @@ impl FileStats {
 pub fn display_coverage_on_screen(
     path_to_report: impl AsRef<Path>,
     modules: &ModuleFilter,
+    exclude_modules: Option<&ModuleFilter>,
+    triage_file: Option<&Path>,
 ) -> Result<()> {
-    let report = Report::load_from_json_file(path_to_report.as_ref())?;
-    let files_to_print = modules.get_all_files_containing_the_modules(&report);
+    let (report, _) = load_report(path_to_report.as_ref(), triage_file)?;
+    let files_to_print = get_all_files_containing_the_modules(&report, modules, exclude_modules);
 
     if files_to_print.is_empty() {
         println!("No matching files found.");
@@ -372,31 +406,46 @@ fn find_mutated_line_number(file_diff: &str) -> Result<usize> {
 pub fn display_mutants_on_screen(
     path_to_report: impl AsRef<Path>,
     modules: &ModuleFilter,
+    exclude_modules: Option<&ModuleFilter>,
     functions: &FunctionFilter,
+    exclude_functions: Option<&FunctionFilter>,
     mutant_filter: &MutantFilter,
+    triage_file: Option<&Path>,
 ) -> Result<()> {
-    let report = Report::load_from_json_file(path_to_report.as_ref())?;
-    let files_to_print = modules.get_all_files_containing_the_modules(&report);
-    let Report { mut files, .. } = report;
+    let (report, triage) = load_report(path_to_report.as_ref(), triage_file)?;
+    let files_to_print = get_all_files_containing_the_modules(&report, modules, exclude_modules);
+    let Report {
+        mut files,
+        killed_by_complete,
+        ..
+    } = report;
+    let killed_by_label = if killed_by_complete {
+        "Killed by"
+    } else {
+        "Killed by (first failing test only)"
+    };
 
     if files_to_print.is_empty() {
         println!("No matching files found.");
         return Ok(());
     };
 
-    let mut all_mutant_stats = Vec::<MutantStats>::new();
+    let mut all_mutant_stats = Vec::<(PathBuf, MutantStats)>::new();
     for file in files_to_print {
         if let Some(mut file_mutant_stats) = files.remove(&file) {
-            if let FunctionFilter::Selected(filtered_funcs) = functions {
-                file_mutant_stats.retain(|m| {
-                    let (_, func) = m
-                        .module_func
-                        .split_once("::")
-                        .expect("invalid function signature in the report file");
-                    filtered_funcs.contains(&func.to_owned())
-                });
-            }
-            all_mutant_stats.extend(file_mutant_stats);
+            file_mutant_stats.retain(|m| {
+                let (module, func) = m
+                    .module_func
+                    .split_once("::")
+                    .expect("invalid function signature in the report file");
+                modules.selects(exclude_modules, module)
+                    && functions.selects(exclude_functions, module, func)
+            });
+            all_mutant_stats.extend(
+                file_mutant_stats
+                    .into_iter()
+                    .map(|stats| (file.clone(), stats)),
+            );
         }
     }
 
@@ -406,21 +455,49 @@ pub fn display_mutants_on_screen(
     };
 
     let f = PatchFormatter::new().with_color();
-    for mutant in all_mutant_stats {
+    for (file, mutant) in all_mutant_stats {
         if mutant_filter.contains_alive() {
-            for diff in mutant.mutants_alive_diffs {
+            let ids = mutant.alive_mutant_ids(&file);
+            for (diff, id) in mutant.mutants_alive_diffs.iter().zip(ids) {
+                println!("----------------------------------------------------------------------------------------------------");
+                println!("{}: Alive mutant [{id}]", mutant.module_func);
+                let patch = Patch::from_str(diff).expect("invalid patch");
+                println!("{}", f.fmt_patch(&patch));
+            }
+        }
+
+        if mutant_filter.contains_triaged() {
+            let ids = mutant.triaged_mutant_ids(&file);
+            for (diff, id) in mutant.mutants_triaged_diffs.iter().zip(ids) {
                 println!("----------------------------------------------------------------------------------------------------");
-                println!("{}: Alive mutant", mutant.module_func);
-                let patch = Patch::from_str(&diff).expect("invalid patch");
+                match triage.as_ref().and_then(|triage| triage.get(&id)) {
+                    Some(entry) => println!(
+                        "{}: Triaged mutant [{id}] ({}: {})",
+                        mutant.module_func, entry.state, entry.reason
+                    ),
+                    None => println!("{}: Triaged mutant [{id}]", mutant.module_func),
+                }
+                let patch = Patch::from_str(diff).expect("invalid patch");
                 println!("{}", f.fmt_patch(&patch));
             }
         }
 
         if mutant_filter.contains_killed() {
-            for diff in mutant.mutants_killed_diff {
+            let ids = mutant.killed_mutant_ids(&file);
+            for (index, (diff, id)) in mutant.mutants_killed_diff.iter().zip(ids).enumerate() {
                 println!("----------------------------------------------------------------------------------------------------");
-                println!("{}: Killed mutant", mutant.module_func);
-                let patch = Patch::from_str(&diff).expect("invalid patch");
+                if let Some(details) = mutant.killed_mutant_details(index) {
+                    println!(
+                        "{}: Killed mutant [{id}] ({})",
+                        mutant.module_func, details.status
+                    );
+                    if !details.killed_by.is_empty() {
+                        println!("{killed_by_label}: {}", details.killed_by.join(", "));
+                    }
+                } else {
+                    println!("{}: Killed mutant [{id}]", mutant.module_func);
+                }
+                let patch = Patch::from_str(diff).expect("invalid patch");
                 println!("{}", f.fmt_patch(&patch));
             }
         }
@@ -431,9 +508,200 @@ pub fn display_mutants_on_screen(
     Ok(())
 }
 
+/// Mutant × test kill matrix.
+#[derive(Default, Debug)]
+struct KillMatrix {
+    /// The set of mutants killed by each test. Mutants are identified by their index.
+    kills: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl KillMatrix {
+    /// Builds the kill matrix from the killing tests recorded for the mutants in the selected modules.
+    ///
+    /// Only the tests defined in the selected modules or killing any of their mutants are included.
+    /// The specification conditions are included if their file contains any of the selected modules.
+    fn new(
+        report: &Report,
+        modules: &ModuleFilter,
+        exclude_modules: Option<&ModuleFilter>,
+    ) -> Self {
+        let all_modules = *modules == ModuleFilter::All && exclude_modules.is_none();
+        let files = get_all_files_containing_the_modules(report, modules, exclude_modules);
+        let mut kills = report
+            .tests
+            .iter()
+            .filter(|test| match condition_file(test) {
+                Some(file) => all_modules || files.contains(file),
+                None => {
+                    test_module(test).is_some_and(|module| modules.selects(exclude_modules, module))
+                },
+            })
+            .map(|test| (test.clone(), BTreeSet::new()))
+            .collect::<BTreeMap<_, _>>();
+
+        let killed_mutants = report
+            .entries()
+            .values()
+            .flatten()
+            .filter(|stats| modules.selects(exclude_modules, &stats.get_module_name()))
+            .flat_map(|stats| &stats.mutants_killed_details);
+        for (mutant, details) in killed_mutants.enumerate() {
+            for test in &details.killed_by {
+                kills.entry(test.clone()).or_default().insert(mutant);
+            }
+        }
+
+        Self { kills }
+    }
+
+    /// Returns `true` if there is no information about the tests.
+    fn is_empty(&self) -> bool {
+        self.kills.is_empty()
+    }
+
+    /// Returns the tests which don't kill any mutant.
+    fn tests_without_kills(&self) -> Vec<&str> {
+        self.kills
+            .iter()
+            .filter(|(_, killed)| killed.is_empty())
+            .map(|(test, _)| test.as_str())
+            .collect()
+    }
+
+    /// Returns the tests whose kill set is a subset of another test's kill set, together with that
+    /// other test.
+    ///
+    /// Out of the tests with the same kill sets, all but the first one are reported as subsumed.
+    fn subsumed_tests(&self) -> Vec<(&str, &str)> {
+        self.kills
+            .iter()
+            .filter(|(_, killed)| !killed.is_empty())
+            .filter_map(|(test, killed)| {
+                self.kills
+                    .iter()
+                    .find(|(other, other_killed)| {
+                        other != &test
+                            && killed.is_subset(other_killed)
+                            && (killed.len() < other_killed.len() || other < &test)
+                    })
+                    .map(|(other, _)| (test.as_str(), other.as_str()))
+            })
+            .collect()
+    }
+
+    /// Returns a subset of tests which kills the same mutants as all the tests together.
+    ///
+    /// Finding the minimal subset is an NP-hard problem, so a greedy approximation is used: the test
+    /// killing the most mutants that are still alive is picked until all mutants are killed.
+    fn minimal_test_subset(&self) -> Vec<&str> {
+        let mut alive = self
+            .kills
+            .values()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>();
+        let mut subset = vec![];
+
+        while !alive.is_empty() {
+            let Some((test, killed)) = self
+                .kills
+                .iter()
+                .rev()
+                .max_by_key(|(_, killed)| killed.intersection(&alive).count())
+            else {
+                break;
+            };
+
+            alive.retain(|mutant| !killed.contains(mutant));
+            subset.push(test.as_str());
+        }
+
+        subset.sort_unstable();
+        subset
+    }
+}
+
+/// Returns the module name of the fully qualified test name, e.g. `Sum` for `0x42::Sum::sum_test`.
+fn test_module(test: &str) -> Option<&str> {
+    let mut segments = test.rsplit("::");
+    segments.next();
+    segments.next()
+}
+
+/// Returns the file of the specification condition, e.g. `sources/Sum.move` for
+/// `sources/Sum.move:7:9: ensures result == x + y;`, or `None` for the unit tests.
+fn condition_file(test: &str) -> Option<&Path> {
+    let (location, _) = test.split_once(": ")?;
+    let mut segments = location.rsplitn(3, ':');
+    let column = segments.next()?;
+    let line = segments.next()?;
+    let file = segments.next()?;
+    (column.parse::<usize>().is_ok() && line.parse::<usize>().is_ok()).then_some(Path::new(file))
+}
+
+/// Displays the tests that kill no mutants, the subsumed tests and a minimal test subset.
+pub fn display_tests_on_screen(
+    path_to_report: impl AsRef<Path>,
+    modules: &ModuleFilter,
+    exclude_modules: Option<&ModuleFilter>,
+    triage_file: Option<&Path>,
+) -> Result<()> {
+    let (report, _) = load_report(path_to_report.as_ref(), triage_file)?;
+
+    // With only the first failing test recorded, the redundant tests can't be found.
+    if !report.killed_by_complete {
+        anyhow::bail!(
+            "the report records only the first failing test for each mutant, run the mutation test with `--fail-fast false` to record all killing tests"
+        );
+    }
+
+    let matrix = KillMatrix::new(&report, modules, exclude_modules);
+
+    if matrix.is_empty() {
+        println!("The report contains no information about the tests.");
+        return Ok(());
+    }
+
+    let mut table = get_formatted_table();
+    table.set_titles(Row::new(vec![
+        Cell::new("Test").with_style(Attr::Bold),
+        Cell::new("Mutants killed").with_style(Attr::Bold),
+    ]));
+    for (test, killed) in &matrix.kills {
+        let color = if killed.is_empty() {
+            COLOR_CRITICAL
+        } else {
+            COLOR_NONE
+        };
+        let mut row = Row::new(vec![Cell::new(test), Cell::new(&killed.len().to_string())]);
+        if let Some(color) = color {
+            row.iter_mut().for_each(|cell| cell.style(color));
+        }
+        table.add_row(row);
+    }
+    table.printstd();
+
+    println!("\nTests that kill no mutants:");
+    for test in matrix.tests_without_kills() {
+        println!("    {test}");
+    }
+
+    println!("\nTests subsumed by other tests:");
+    for (test, other) in matrix.subsumed_tests() {
+        println!("    {test} (subsumed by {other})");
+    }
+
+    println!("\nMinimal test subset keeping the same mutation score:");
+    for test in matrix.minimal_test_subset() {
+        println!("    {test}");
+    }
+
+    Ok(())
+}
+
 /// Summarize the report.
-pub fn display_summary(path_to_report: impl AsRef<Path>) -> Result<()> {
-    let report = Report::load_from_json_file(path_to_report.as_ref())?;
+pub fn display_summary(path_to_report: impl AsRef<Path>, triage_file: Option<&Path>) -> Result<()> {
+    let (report, _) = load_report(path_to_report.as_ref(), triage_file)?;
     report.print_table();
     Ok(())
 }
@@ -464,31 +732,190 @@ mod tests {
         fs::File::create(path2).unwrap();
 
         let modules = ModuleFilter::All;
-        let ret = display_coverage_on_screen(&report_path, &modules);
+        let ret = display_coverage_on_screen(&report_path, &modules, None, None);
         assert!(ret.is_ok());
 
         let functions = FunctionFilter::All;
         let mutant_filter = MutantFilter::All;
-        let ret = display_mutants_on_screen(&report_path, &modules, &functions, &mutant_filter);
+        let ret = display_mutants_on_screen(
+            &report_path,
+            &modules,
+            None,
+            &functions,
+            None,
+            &mutant_filter,
+            None,
+        );
         assert!(ret.is_ok());
 
-        let ret = display_summary(report_path);
+        let ret = display_summary(report_path, None);
         assert!(ret.is_ok());
     }
 
+    #[test]
+    fn kill_matrix_finds_redundant_tests() {
+        use crate::report::{MiniReport, MutantStatus};
+
+        let mut report = Report::new("package_dir".into());
+        report.tests = ["test_a", "test_b", "test_c", "test_d", "test_e"]
+            .into_iter()
+            .map(|test| format!("0x1::m::{test}"))
+            .collect();
+
+        let path = PathBuf::from("sources/m.move");
+        let kills: [&[&str]; 3] = [&["test_a", "test_b", "test_d"], &["test_a", "test_d"], &[
+            "test_c",
+        ]];
+        for killed_by in kills {
+            report.add_mutant_result(
+                MiniReport::new(
+                    path.clone(),
+                    "m::f".to_owned(),
+                    MutantStatus::KilledByTest,
+                    String::new(),
+                )
+                .with_killed_by(killed_by.iter().map(|t| format!("0x1::m::{t}")).collect()),
+            );
+        }
+
+        let matrix = KillMatrix::new(&report, &ModuleFilter::All, None);
+        assert_eq!(matrix.tests_without_kills(), vec!["0x1::m::test_e"]);
+        assert_eq!(matrix.subsumed_tests(), vec![
+            ("0x1::m::test_b", "0x1::m::test_a"),
+            ("0x1::m::test_d", "0x1::m::test_a")
+        ]);
+        assert_eq!(matrix.minimal_test_subset(), vec![
+            "0x1::m::test_a",
+            "0x1::m::test_c"
+        ]);
+    }
+
+    #[test]
+    fn kill_matrix_includes_only_tests_for_selected_modules() {
+        use crate::report::{MiniReport, MutantStatus};
+
+        let mut report = Report::new("package_dir".into());
+        report.tests = [
+            "0x1::m::test_m",
+            "0x1::n::test_n",
+            "0x1::n_tests::test_m_and_n",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let kills = [("m::f", "0x1::m::test_m"), ("n::f", "0x1::n::test_n")];
+        for (qname, test) in kills {
+            report.add_mutant_result(
+                MiniReport::new(
+                    PathBuf::from("sources/m.move"),
+                    qname.to_owned(),
+                    MutantStatus::KilledByTest,
+                    String::new(),
+                )
+                .with_killed_by(vec![
+                    test.to_owned(),
+                    "0x1::n_tests::test_m_and_n".to_owned(),
+                ]),
+            );
+        }
+
+        let modules = ModuleFilter::Selected(vec!["m".to_owned()]);
+        let matrix = KillMatrix::new(&report, &modules, None);
+        assert_eq!(matrix.kills.keys().collect::<Vec<_>>(), vec![
+            "0x1::m::test_m",
+            "0x1::n_tests::test_m_and_n"
+        ]);
+        assert!(matrix.tests_without_kills().is_empty());
+    }
+
+    #[test]
+    fn kill_matrix_includes_spec_conditions_for_selected_modules() {
+        use crate::report::{MiniReport, MutantStatus};
+
+        let sum_ensures = "sources/Sum.move:7:9: ensures result == x + y;";
+        let sum_aborts = "sources/Sum.move:8:9: aborts_if x + y > MAX_U64;";
+        let option_ensures = "sources/Opt.move:5:9: ensures result == option::none();";
+
+        let mut report = Report::new("package_dir".into());
+        report.tests = [sum_ensures, sum_aborts, option_ensures]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let mutants = [
+            ("sources/Sum.move", "Sum::sum"),
+            ("sources/Opt.move", "Opt::get"),
+        ];
+        for (file, qname) in mutants {
+            report.add_mutant_result(
+                MiniReport::new(
+                    PathBuf::from(file),
+                    qname.to_owned(),
+                    MutantStatus::KilledByTest,
+                    String::new(),
+                )
+                .with_killed_by(vec![
+                    if qname == "Sum::sum" {
+                        sum_ensures.to_owned()
+                    } else {
+                        option_ensures.to_owned()
+                    },
+                ]),
+            );
+        }
+
+        let matrix = KillMatrix::new(&report, &ModuleFilter::All, None);
+        assert_eq!(matrix.kills.len(), 3);
+        assert_eq!(matrix.tests_without_kills(), vec![sum_aborts]);
+
+        let modules = ModuleFilter::Selected(vec!["Sum".to_owned()]);
+        let matrix = KillMatrix::new(&report, &modules, None);
+        assert_eq!(matrix.kills.keys().collect::<Vec<_>>(), vec![
+            sum_ensures,
+            sum_aborts
+        ]);
+        assert_eq!(matrix.tests_without_kills(), vec![sum_aborts]);
+    }
+
+    #[test]
+    fn tests_view_requires_all_killing_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        let report_path = dir.path().join("report.txt");
+
+        let mut report = Report::new(dir.path().to_path_buf());
+        report.save_to_json_file(&report_path).unwrap();
+        assert!(display_tests_on_screen(&report_path, &ModuleFilter::All, None, None).is_err());
+
+        report.killed_by_complete = true;
+        report.save_to_json_file(&report_path).unwrap();
+        assert!(display_tests_on_screen(&report_path, &ModuleFilter::All, None, None).is_ok());
+    }
+
     #[test]
     fn report_file_not_found() {
         let path = PathBuf::from("/path/to/non/existing/file");
         let modules = ModuleFilter::All;
-        let ret = display_coverage_on_screen(&path, &modules);
+        let ret = display_coverage_on_screen(&path, &modules, None, None);
         assert!(ret.is_err());
 
         let functions = FunctionFilter::All;
         let mutant_filter = MutantFilter::Alive;
-        let ret = display_mutants_on_screen(&path, &modules, &functions, &mutant_filter);
+        let ret = display_mutants_on_screen(
+            &path,
+            &modules,
+            None,
+            &functions,
+            None,
+            &mutant_filter,
+            None,
+        );
+        assert!(ret.is_err());
+
+        let ret = display_tests_on_screen(&path, &modules, None, None);
         assert!(ret.is_err());
 
-        let ret = display_summary(path);
+        let ret = display_summary(path, None);
         assert!(ret.is_err());
     }
 }
@@ -0,0 +1,172 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+/// Separator between the module and the function name in the qualified patterns.
+const QUALIFIER_SEPARATOR: &str = "::";
+
+/// Checks whether the `text` matches the glob `pattern`.
+///
+/// The `*` wildcard matches any sequence of characters (including an empty one) and the `?`
+/// wildcard matches exactly one character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let (mut p, mut t) = (0, 0);
+    // The position of the last `*` in the pattern and the text position it's matched up to.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            },
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            },
+            _ => match backtrack {
+                // Let the last `*` match one more character.
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Splits the list of patterns given on the command line.
+fn split_patterns(s: &str) -> Vec<String> {
+    s.split(&[';', '-', ',']).map(String::from).collect()
+}
+
+/// Filter for the modules, given as a list of names or glob patterns (e.g. `pool*`).
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum ModuleFilter {
+    #[default]
+    All,
+    Selected(Vec<String>),
+}
+
+impl ModuleFilter {
+    /// Checks whether the module is matched by the filter.
+    pub fn matches(&self, module: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Selected(patterns) => patterns.iter().any(|p| glob_match(p, module)),
+        }
+    }
+
+    /// Checks whether the module is matched by the filter and isn't matched by the `exclude` one.
+    pub fn selects(&self, exclude: Option<&ModuleFilter>, module: &str) -> bool {
+        self.matches(module) && !exclude.is_some_and(|e| e.matches(module))
+    }
+}
+
+impl FromStr for ModuleFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(ModuleFilter::All),
+            _ => Ok(ModuleFilter::Selected(split_patterns(s))),
+        }
+    }
+}
+
+/// Filter for the functions, given as a list of names or glob patterns.
+///
+/// A pattern without the module (e.g. `swap_*`) matches the functions in all modules, while
+/// a module-qualified pattern (e.g. `pool::swap_*`) matches only the functions in the modules
+/// matched by its module part.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum FunctionFilter {
+    #[default]
+    All,
+    Selected(Vec<String>),
+}
+
+impl FunctionFilter {
+    /// Checks whether the function in the given module is matched by the filter.
+    pub fn matches(&self, module: &str, function: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Selected(patterns) => {
+                patterns
+                    .iter()
+                    .any(|pattern| match pattern.rsplit_once(QUALIFIER_SEPARATOR) {
+                        Some((module_pattern, function_pattern)) => {
+                            glob_match(module_pattern, module)
+                                && glob_match(function_pattern, function)
+                        },
+                        None => glob_match(pattern, function),
+                    })
+            },
+        }
+    }
+
+    /// Checks whether the function is matched by the filter and isn't matched by the `exclude` one.
+    pub fn selects(&self, exclude: Option<&FunctionFilter>, module: &str, function: &str) -> bool {
+        self.matches(module, function) && !exclude.is_some_and(|e| e.matches(module, function))
+    }
+}
+
+impl FromStr for FunctionFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(FunctionFilter::All),
+            _ => Ok(FunctionFilter::Selected(split_patterns(s))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_patterns_match() {
+        assert!(glob_match("swap", "swap"));
+        assert!(!glob_match("swap", "swap_exact"));
+        assert!(glob_match("swap_*", "swap_exact"));
+        assert!(glob_match("swap_*", "swap_"));
+        assert!(glob_match("*_bindings", "pool_bindings"));
+        assert!(glob_match("*a*b*", "xxaxxbxx"));
+        assert!(!glob_match("*a*b", "xxaxxbxx"));
+        assert!(glob_match("f?o", "foo"));
+        assert!(!glob_match("f?o", "fo"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn qualified_function_patterns_match_only_their_modules() {
+        let filter = FunctionFilter::from_str("pool::swap_*,init").unwrap();
+        assert!(filter.matches("pool", "swap_exact"));
+        assert!(!filter.matches("router", "swap_exact"));
+        assert!(filter.matches("router", "init"));
+
+        let exclude = FunctionFilter::from_str("*::swap_exact").unwrap();
+        assert!(!filter.selects(Some(&exclude), "pool", "swap_exact"));
+        assert!(filter.selects(Some(&exclude), "pool", "swap_all"));
+    }
+
+    #[test]
+    fn excluded_modules_are_not_selected() {
+        let exclude = ModuleFilter::from_str("*_bindings;admin").unwrap();
+        assert!(ModuleFilter::All.selects(Some(&exclude), "pool"));
+        assert!(!ModuleFilter::All.selects(Some(&exclude), "pool_bindings"));
+        assert!(!ModuleFilter::All.selects(Some(&exclude), "admin"));
+        assert!(ModuleFilter::All.selects(None, "admin"));
+    }
+}
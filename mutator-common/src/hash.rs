@@ -0,0 +1,37 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+/// FNV-1a parameters, the hash must not change between the runs and the tool versions.
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01B3;
+
+/// Computes a hash of the given fields which doesn't change between the runs, the platforms and
+/// the tool versions.
+///
+/// Returns the hash as 16 hexadecimal digits.
+pub fn stable_hash<'a>(fields: impl IntoIterator<Item = &'a [u8]>) -> String {
+    let mut hash = FNV_OFFSET_BASIS;
+    for field in fields {
+        // The terminating byte separates the fields, it never appears in valid UTF-8.
+        for byte in field.iter().chain(&[0xFF]) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_hash_is_fixed_and_separates_fields() {
+        assert_eq!(stable_hash([b"Sum".as_slice(), b"sum"]), "a43cf47334616685");
+        assert_ne!(
+            stable_hash([b"ab".as_slice(), b"c"]),
+            stable_hash([b"a".as_slice(), b"bc"])
+        );
+    }
+}
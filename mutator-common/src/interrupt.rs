@@ -0,0 +1,46 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    OnceLock,
+};
+
+/// The exit code used when the tool is interrupted for the second time.
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Set once the interruption signal is received.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// The result of installing the signal handler, which can be installed only once per process.
+static HANDLER: OnceLock<Result<(), String>> = OnceLock::new();
+
+/// Installs the handler for the SIGINT and SIGTERM signals.
+///
+/// The first signal only marks the run as interrupted, so the tools can stop scheduling new mutants,
+/// wait for the ones in progress and produce a partial report. The second signal terminates the
+/// process immediately.
+///
+/// It's safe to call this function multiple times.
+pub fn install_handler() -> anyhow::Result<()> {
+    HANDLER
+        .get_or_init(|| {
+            ctrlc::set_handler(|| {
+                if INTERRUPTED.swap(true, Ordering::SeqCst) {
+                    std::process::exit(INTERRUPTED_EXIT_CODE);
+                }
+                eprintln!(
+                    "\nInterrupted, waiting for the mutants in progress to finish (press Ctrl-C again to exit immediately)"
+                );
+            })
+            .map_err(|e| e.to_string())
+        })
+        .clone()
+        .map_err(|e| anyhow::Error::msg(format!("cannot install the signal handler: {e}")))
+}
+
+/// Checks whether the interruption signal has been received.
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
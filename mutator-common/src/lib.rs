@@ -5,11 +5,26 @@
 /// Benchmarking.
 pub mod benchmark;
 
+/// Incremental saving of the mutant results, so an interrupted run can be resumed.
+pub mod checkpoint;
+
 /// A module for displaying reports in a nice fashion.
 pub mod display_report;
 
+/// Module and function filters shared by the tools and the report displaying.
+pub mod filter;
+
+/// Hashing which gives the same results across the runs and the tool versions.
+pub mod hash;
+
+/// Graceful handling of the interruption signals.
+pub mod interrupt;
+
 /// A module for generating concise, valuable reports.
 pub mod report;
 
 /// A path setup container for packages under test.
 pub mod tmp_package_dir;
+
+/// Triage of the surviving mutants which were reviewed and accepted.
+pub mod triage;
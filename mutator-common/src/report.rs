@@ -3,25 +3,65 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::triage::{self, Triage};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::BTreeMap,
-    fs,
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
     path::{Path, PathBuf},
 };
 use tabled::{builder::Builder, settings::Style};
 
 /// The final status of the mutant after running the tests on it.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum MutantStatus {
-    /// Killed mutant.
-    Killed,
+    /// Mutant killed by a failing test (or by a failing verification in case of the spec testing).
+    KilledByTest,
+    /// Mutant killed because it doesn't compile.
+    KilledByCompileError,
+    /// Mutant killed because the tests ran out of gas.
+    OutOfGas,
+    /// Mutant killed because the tests didn't finish in time.
+    Timeout,
+    /// The tool failed to run the tests on the mutant.
+    ToolError,
+    /// The mutant wasn't tested at all.
+    Skipped,
+    /// The mutant compiles to the same bytecode as the original code, so it wasn't tested.
+    Equivalent,
     /// Alive mutant.
     Alive,
 }
 
+impl MutantStatus {
+    /// Checks whether the status means the mutant is killed.
+    pub fn is_killed(&self) -> bool {
+        matches!(
+            self,
+            Self::KilledByTest | Self::KilledByCompileError | Self::OutOfGas | Self::Timeout
+        )
+    }
+}
+
+impl fmt::Display for MutantStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::KilledByTest => "killed by test",
+            Self::KilledByCompileError => "killed by compile error",
+            Self::OutOfGas => "out of gas",
+            Self::Timeout => "timeout",
+            Self::ToolError => "tool error",
+            Self::Skipped => "skipped",
+            Self::Equivalent => "equivalent",
+            Self::Alive => "alive",
+        };
+        write!(f, "{status}")
+    }
+}
+
 /// This struct represents a report single mutation test.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiniReport {
     /// The original file name.
     pub original_file: PathBuf,
@@ -31,6 +71,14 @@ pub struct MiniReport {
     pub mutant_status: MutantStatus,
     /// A file difference that identifies mutants.
     pub diff: String,
+    /// The tests which killed the mutant.
+    #[serde(default)]
+    pub killed_by: Vec<String>,
+    /// The stable identity of the mutant computed by the mutator.
+    ///
+    /// Empty if the mutator report doesn't contain it.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub mutant_id: String,
 }
 
 impl MiniReport {
@@ -46,8 +94,22 @@ impl MiniReport {
             qname,
             mutant_status,
             diff,
+            killed_by: vec![],
+            mutant_id: String::new(),
         }
     }
+
+    /// Sets the tests which killed the mutant.
+    pub fn with_killed_by(mut self, killed_by: Vec<String>) -> Self {
+        self.killed_by = killed_by;
+        self
+    }
+
+    /// Sets the stable identity of the mutant.
+    pub fn with_mutant_id(mut self, mutant_id: String) -> Self {
+        self.mutant_id = mutant_id;
+        self
+    }
 }
 
 /// This struct represents a report of the mutation and spec testing.
@@ -60,6 +122,16 @@ pub struct Report {
     pub files: BTreeMap<PathBuf, Vec<MutantStats>>,
     /// Package directory location.
     pub package_dir: PathBuf,
+    /// The names of all tests executed on the original package (or the specification conditions
+    /// verified by the prover).
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tests: BTreeSet<String>,
+    /// Whether all tests killing each mutant are recorded.
+    ///
+    /// The tests stop at the first failure in the `fail_fast` mode, so only the first failing test is
+    /// recorded for each mutant then.
+    #[serde(default)]
+    pub killed_by_complete: bool,
 }
 
 impl Report {
@@ -68,6 +140,8 @@ impl Report {
         Self {
             files: BTreeMap::new(),
             package_dir,
+            tests: BTreeSet::new(),
+            killed_by_complete: false,
         }
     }
 
@@ -94,6 +168,151 @@ impl Report {
         self.total_count(|v| v.killed)
     }
 
+    /// Returns the number of killed mutants which don't compile.
+    pub fn mutants_killed_by_compile_error(&self) -> u32 {
+        self.total_count(|v| v.killed_by_compile_error)
+    }
+
+    /// Returns the number of killed mutants for which the tests ran out of gas.
+    pub fn mutants_out_of_gas(&self) -> u32 {
+        self.total_count(|v| v.out_of_gas)
+    }
+
+    /// Returns the number of killed mutants for which the tests didn't finish in time.
+    pub fn mutants_timed_out(&self) -> u32 {
+        self.total_count(|v| v.timed_out)
+    }
+
+    /// Returns the number of mutants which couldn't be tested due to the tool errors.
+    pub fn mutants_with_tool_errors(&self) -> u32 {
+        self.total_count(|v| v.tool_errors)
+    }
+
+    /// Returns the number of mutants which weren't tested.
+    pub fn mutants_skipped(&self) -> u32 {
+        self.total_count(|v| v.skipped)
+    }
+
+    /// Returns the number of mutants which weren't tested since they are equivalent to the original code.
+    pub fn mutants_equivalent(&self) -> u32 {
+        self.total_count(|v| v.equivalent)
+    }
+
+    /// Returns the number of surviving mutants excluded from the score by the triage file.
+    pub fn mutants_triaged(&self) -> u32 {
+        self.total_count(|v| v.mutants_triaged_diffs.len() as u32)
+    }
+
+    /// Excludes the surviving mutants listed in the triage from the tested mutants and the score.
+    ///
+    /// The mutants excluded by a previous call are restored first, so the triage can be applied
+    /// again after the triage file changes.
+    ///
+    /// Returns the identities of the triaged mutants which are not in the report, although their
+    /// functions are.
+    pub fn apply_triage(&mut self, triage: &Triage) -> Vec<String> {
+        let mut existing = BTreeSet::new();
+        let mut functions = BTreeSet::new();
+
+        for (file, stats) in &mut self.files {
+            for stat in stats {
+                stat.mutants_alive_ids = stat.alive_mutant_ids(file);
+                stat.mutants_triaged_ids = stat.triaged_mutant_ids(file);
+                functions.insert(stat.module_func.clone());
+
+                let restored = std::mem::take(&mut stat.mutants_triaged_diffs);
+                stat.tested += restored.len() as u32;
+                stat.mutants_alive_diffs.extend(restored);
+                stat.mutants_alive_ids.append(&mut stat.mutants_triaged_ids);
+
+                existing.extend(stat.killed_mutant_ids(file));
+                existing.extend(stat.mutants_alive_ids.iter().cloned());
+
+                let (triaged, alive): (Vec<_>, Vec<_>) =
+                    std::mem::take(&mut stat.mutants_alive_diffs)
+                        .into_iter()
+                        .zip(std::mem::take(&mut stat.mutants_alive_ids))
+                        .partition(|(_, id)| triage.get(id).is_some());
+                stat.tested -= triaged.len() as u32;
+                (stat.mutants_alive_diffs, stat.mutants_alive_ids) = alive.into_iter().unzip();
+                (stat.mutants_triaged_diffs, stat.mutants_triaged_ids) =
+                    triaged.into_iter().unzip();
+            }
+        }
+
+        triage
+            .entries()
+            .filter(|entry| functions.contains(&entry.function) && !existing.contains(&entry.id))
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+
+    /// Returns the mutation score in percents.
+    ///
+    /// Mutants which don't compile are not detected by the tests, so they are excluded from the score.
+    /// Mutants for which the tests ran out of gas or time didn't fail any assertion either, so they
+    /// are excluded as well and reported separately.
+    pub fn mutation_score(&self) -> f64 {
+        let undetected = self.mutants_killed_by_compile_error()
+            + self.mutants_out_of_gas()
+            + self.mutants_timed_out();
+        let killed = self.mutants_killed() - undetected;
+        let tested = self.mutants_tested() - undetected;
+
+        if tested == 0 {
+            0.0
+        } else {
+            f64::from(killed) / f64::from(tested) * 100.0
+        }
+    }
+
+    /// Records the result of testing a single mutant.
+    pub fn add_mutant_result(&mut self, mini_report: MiniReport) {
+        let MiniReport {
+            original_file,
+            qname,
+            mutant_status,
+            diff,
+            killed_by,
+            mutant_id,
+        } = mini_report;
+
+        // The reports of the older mutator versions don't contain the identities.
+        let mutant_id = if mutant_id.is_empty() {
+            triage::mutant_id(&original_file, &qname, &diff)
+        } else {
+            mutant_id
+        };
+
+        self.increment_stat(&original_file, &qname, |stat| match mutant_status {
+            MutantStatus::Skipped => stat.skipped += 1,
+            MutantStatus::Equivalent => stat.equivalent += 1,
+            MutantStatus::ToolError => stat.tool_errors += 1,
+            MutantStatus::Alive => {
+                stat.tested += 1;
+                stat.mutants_alive_diffs.push(diff);
+                stat.mutants_alive_ids.push(mutant_id);
+            },
+            MutantStatus::KilledByTest
+            | MutantStatus::KilledByCompileError
+            | MutantStatus::OutOfGas
+            | MutantStatus::Timeout => {
+                stat.tested += 1;
+                stat.killed += 1;
+                match mutant_status {
+                    MutantStatus::KilledByCompileError => stat.killed_by_compile_error += 1,
+                    MutantStatus::OutOfGas => stat.out_of_gas += 1,
+                    MutantStatus::Timeout => stat.timed_out += 1,
+                    _ => {},
+                }
+                stat.mutants_killed_diff.push(diff);
+                stat.mutants_killed_ids.push(mutant_id);
+                stat.mutants_killed_details
+                    .push(KilledMutantDetails::new(mutant_status, killed_by));
+            },
+        });
+    }
+
     /// Add a diff for a survived mutant.
     pub fn add_mutants_alive_diff(&mut self, path: &Path, module_func: &str, diff: &str) {
         let entry = self
@@ -101,11 +320,14 @@ impl Report {
             .entry(path.to_path_buf())
             .or_insert(vec![MutantStats::new(module_func)]);
 
+        let id = triage::mutant_id(path, module_func, diff);
         if let Some(stat) = entry.iter_mut().find(|s| s.module_func == module_func) {
             stat.mutants_alive_diffs.push(diff.to_owned());
+            stat.mutants_alive_ids.push(id);
         } else {
             let mut new_entry = MutantStats::new(module_func);
             new_entry.mutants_alive_diffs.push(diff.to_owned());
+            new_entry.mutants_alive_ids.push(id);
             entry.push(new_entry);
         }
     }
@@ -117,11 +339,14 @@ impl Report {
             .entry(path.to_path_buf())
             .or_insert(vec![MutantStats::new(module_func)]);
 
+        let id = triage::mutant_id(path, module_func, diff);
         if let Some(stat) = entry.iter_mut().find(|s| s.module_func == module_func) {
             stat.mutants_killed_diff.push(diff.to_owned());
+            stat.mutants_killed_ids.push(id);
         } else {
             let mut new_entry = MutantStats::new(module_func);
             new_entry.mutants_killed_diff.push(diff.to_owned());
+            new_entry.mutants_killed_ids.push(id);
             entry.push(new_entry);
         }
     }
@@ -178,13 +403,52 @@ impl Report {
         println!("{table}");
         println!("Total mutants tested: {}", self.mutants_tested());
         println!("Total mutants killed: {}", self.mutants_killed());
+
+        let breakdown = [
+            (
+                "killed by compile errors",
+                self.mutants_killed_by_compile_error(),
+            ),
+            ("ran out of gas", self.mutants_out_of_gas()),
+            ("timed out", self.mutants_timed_out()),
+        ];
+        for (description, count) in breakdown.iter().filter(|(_, count)| *count > 0) {
+            println!("    - {description}: {count}");
+        }
+
+        if self.mutants_with_tool_errors() > 0 {
+            println!(
+                "Mutants not tested due to tool errors: {}",
+                self.mutants_with_tool_errors()
+            );
+        }
+        if self.mutants_skipped() > 0 {
+            println!("Mutants skipped: {}", self.mutants_skipped());
+        }
+        if self.mutants_equivalent() > 0 {
+            println!(
+                "Equivalent mutants (not tested): {}",
+                self.mutants_equivalent()
+            );
+        }
+        if self.mutants_triaged() > 0 {
+            println!(
+                "Triaged surviving mutants (not counted): {}",
+                self.mutants_triaged()
+            );
+        }
+
+        println!(
+            "Mutation score (without mutants that don't compile, run out of gas or time out): {:.2}%",
+            self.mutation_score()
+        );
         println!(); // Empty line before the end
     }
 
     // Internal function to increment the chosen stat.
-    fn increment_stat<F>(&mut self, path: &Path, module_func: &str, mut increment: F)
+    fn increment_stat<F>(&mut self, path: &Path, module_func: &str, increment: F)
     where
-        F: FnMut(&mut MutantStats),
+        F: FnOnce(&mut MutantStats),
     {
         let entry = self
             .files
@@ -226,10 +490,43 @@ pub struct MutantStats {
     pub tested: u32,
     /// The number of mutants killed.
     pub killed: u32,
+    /// The number of killed mutants which don't compile.
+    #[serde(default)]
+    pub killed_by_compile_error: u32,
+    /// The number of killed mutants for which the tests ran out of gas.
+    #[serde(default)]
+    pub out_of_gas: u32,
+    /// The number of killed mutants for which the tests didn't finish in time.
+    #[serde(default)]
+    pub timed_out: u32,
+    /// The number of mutants which couldn't be tested due to the tool errors.
+    #[serde(default)]
+    pub tool_errors: u32,
+    /// The number of mutants which weren't tested.
+    #[serde(default)]
+    pub skipped: u32,
+    /// The number of mutants which weren't tested since they are equivalent to the original code.
+    #[serde(default)]
+    pub equivalent: u32,
     /// The list of survived mutants.
     pub mutants_alive_diffs: Vec<String>,
     /// The list of killed mutants.
     pub mutants_killed_diff: Vec<String>,
+    /// The details about the killed mutants, in the same order as `mutants_killed_diff`.
+    #[serde(default)]
+    pub mutants_killed_details: Vec<KilledMutantDetails>,
+    /// The survived mutants excluded from the tested mutants by the triage file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutants_triaged_diffs: Vec<String>,
+    /// The identities of the survived mutants, in the same order as `mutants_alive_diffs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutants_alive_ids: Vec<String>,
+    /// The identities of the killed mutants, in the same order as `mutants_killed_diff`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutants_killed_ids: Vec<String>,
+    /// The identities of the triaged mutants, in the same order as `mutants_triaged_diffs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutants_triaged_ids: Vec<String>,
 }
 
 impl MutantStats {
@@ -249,6 +546,58 @@ impl MutantStats {
             .expect("module name not found")
             .to_owned()
     }
+
+    /// Get the details about the killed mutant with the given index in `mutants_killed_diff`.
+    ///
+    /// Reports generated by the older versions of the tools don't contain the details.
+    pub fn killed_mutant_details(&self, index: usize) -> Option<&KilledMutantDetails> {
+        self.mutants_killed_details.get(index)
+    }
+
+    /// Get the identities of the survived mutants, in the same order as `mutants_alive_diffs`.
+    pub fn alive_mutant_ids(&self, file: &Path) -> Vec<String> {
+        self.mutant_ids(file, &self.mutants_alive_diffs, &self.mutants_alive_ids)
+    }
+
+    /// Get the identities of the killed mutants, in the same order as `mutants_killed_diff`.
+    pub fn killed_mutant_ids(&self, file: &Path) -> Vec<String> {
+        self.mutant_ids(file, &self.mutants_killed_diff, &self.mutants_killed_ids)
+    }
+
+    /// Get the identities of the triaged mutants, in the same order as `mutants_triaged_diffs`.
+    pub fn triaged_mutant_ids(&self, file: &Path) -> Vec<String> {
+        self.mutant_ids(file, &self.mutants_triaged_diffs, &self.mutants_triaged_ids)
+    }
+
+    // Reports generated by the older versions of the tools don't contain the identities, so they
+    // are computed from the diffs.
+    fn mutant_ids(&self, file: &Path, diffs: &[String], ids: &[String]) -> Vec<String> {
+        if ids.len() == diffs.len() {
+            return ids.to_vec();
+        }
+
+        diffs
+            .iter()
+            .map(|diff| triage::mutant_id(file, &self.module_func, diff))
+            .collect()
+    }
+}
+
+/// The details about a killed mutant.
+#[derive(Debug, Serialize, Deserialize, PartialEq, PartialOrd, Clone)]
+pub struct KilledMutantDetails {
+    /// The reason why the mutant was killed.
+    pub status: MutantStatus,
+    /// The tests which killed the mutant.
+    #[serde(default)]
+    pub killed_by: Vec<String>,
+}
+
+impl KilledMutantDetails {
+    /// Creates new details for the killed mutant.
+    pub fn new(status: MutantStatus, killed_by: Vec<String>) -> Self {
+        Self { status, killed_by }
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +695,134 @@ mod tests {
                 && s.mutants_alive_diffs.contains(&diff.to_owned())));
     }
 
+    #[test]
+    fn add_mutant_result_counts_statuses() {
+        let mut report = Report::new("package_dir".into());
+        let path = PathBuf::from("path/to/file");
+        let qname = "module::func";
+        for status in [
+            MutantStatus::KilledByTest,
+            MutantStatus::KilledByCompileError,
+            MutantStatus::OutOfGas,
+            MutantStatus::Timeout,
+            MutantStatus::ToolError,
+            MutantStatus::Skipped,
+            MutantStatus::Equivalent,
+            MutantStatus::Alive,
+        ] {
+            report.add_mutant_result(MiniReport::new(
+                path.clone(),
+                qname.to_owned(),
+                status,
+                status.to_string(),
+            ));
+        }
+
+        let stat = &report.entries().get(&path).unwrap()[0];
+        assert_eq!(stat.tested, 5);
+        assert_eq!(stat.killed, 4);
+        assert_eq!(stat.killed_by_compile_error, 1);
+        assert_eq!(stat.out_of_gas, 1);
+        assert_eq!(stat.timed_out, 1);
+        assert_eq!(stat.tool_errors, 1);
+        assert_eq!(stat.skipped, 1);
+        assert_eq!(stat.equivalent, 1);
+        assert_eq!(stat.mutants_alive_diffs, vec!["alive"]);
+        assert_eq!(stat.mutants_killed_diff.len(), 4);
+        assert_eq!(
+            stat.killed_mutant_details(1).unwrap().status,
+            MutantStatus::KilledByCompileError
+        );
+    }
+
+    #[test]
+    fn add_mutant_result_keeps_killing_tests() {
+        let mut report = Report::new("package_dir".into());
+        let path = PathBuf::from("path/to/file");
+        let killed_by = vec!["0x1::module::test_a".to_owned()];
+        report.add_mutant_result(
+            MiniReport::new(
+                path.clone(),
+                "module::func".to_owned(),
+                MutantStatus::KilledByTest,
+                "diff".to_owned(),
+            )
+            .with_killed_by(killed_by.clone()),
+        );
+
+        let stat = &report.entries().get(&path).unwrap()[0];
+        assert_eq!(stat.killed_mutant_details(0).unwrap().killed_by, killed_by);
+    }
+
+    #[test]
+    fn mutation_score_excludes_mutants_which_do_not_compile() {
+        let mut report = Report::new("package_dir".into());
+        let path = PathBuf::from("path/to/file");
+        for status in [
+            MutantStatus::KilledByTest,
+            MutantStatus::KilledByCompileError,
+            MutantStatus::KilledByCompileError,
+            MutantStatus::Alive,
+        ] {
+            report.add_mutant_result(MiniReport::new(
+                path.clone(),
+                "module::func".to_owned(),
+                status,
+                String::new(),
+            ));
+        }
+
+        assert_eq!(report.mutation_score(), 50.0);
+    }
+
+    #[test]
+    fn mutation_score_excludes_mutants_which_run_out_of_gas_or_time() {
+        let mut report = Report::new("package_dir".into());
+        let path = PathBuf::from("path/to/file");
+        for status in [
+            MutantStatus::KilledByTest,
+            MutantStatus::OutOfGas,
+            MutantStatus::Timeout,
+            MutantStatus::Timeout,
+            MutantStatus::Alive,
+        ] {
+            report.add_mutant_result(MiniReport::new(
+                path.clone(),
+                "module::func".to_owned(),
+                status,
+                String::new(),
+            ));
+        }
+
+        assert_eq!(report.mutation_score(), 50.0);
+    }
+
+    #[test]
+    fn old_reports_without_new_fields_are_loaded() {
+        let report = Report::load_from_str(
+            r#"{
+                "files": {
+                    "sources/Sum.move": [
+                        {
+                            "module_func": "Sum::sum",
+                            "tested": 1,
+                            "killed": 1,
+                            "mutants_alive_diffs": [],
+                            "mutants_killed_diff": ["diff"]
+                        }
+                    ]
+                },
+                "package_dir": "package_dir"
+            }"#,
+        )
+        .unwrap();
+
+        let stat = &report.entries()[&PathBuf::from("sources/Sum.move")][0];
+        assert_eq!(stat.killed_by_compile_error, 0);
+        assert!(stat.killed_mutant_details(0).is_none());
+        assert!(!report.killed_by_complete);
+    }
+
     #[test]
     fn add_mutants_alive_diff_adds_diff_to_existing_module() {
         let mut report = Report::new("package_dir".into());
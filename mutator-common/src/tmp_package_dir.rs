@@ -71,6 +71,13 @@ pub fn strip_path_prefix<P: AsRef<Path>>(path: P) -> Result<PathBuf> {
     Ok(PathBuf::from(&original_file[sources_dir_idx..]))
 }
 
+/// Removes the output directory with the working copies of the package.
+pub fn cleanup_outdir(outdir: &Path) -> Result<()> {
+    fs::remove_dir_all(outdir)?;
+    trace!("removed {}", outdir.display());
+    Ok(())
+}
+
 /// Rewrite the manifest file to use absolute paths.
 ///
 /// # Arguments
@@ -0,0 +1,314 @@
+// Copyright © Eiger
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{hash::stable_hash, report::Report};
+use anyhow::{Context, Result};
+use diffy::{Line, Patch};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Component, Path},
+};
+
+/// The name of the triage file looked up in the package directory.
+pub const TRIAGE_FILE: &str = "mutation-triage.toml";
+
+/// The outcome of the review of a surviving mutant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriageState {
+    /// The mutant behaves exactly like the original code, so no test can kill it.
+    Equivalent,
+    /// The mutant is out of the scope of the test suite.
+    Ignored,
+    /// The mutant should be killed, but it's accepted for now.
+    Todo,
+}
+
+impl fmt::Display for TriageState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            Self::Equivalent => "equivalent",
+            Self::Ignored => "ignored",
+            Self::Todo => "todo",
+        };
+        write!(f, "{state}")
+    }
+}
+
+/// A single reviewed mutant.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TriageEntry {
+    /// The identity of the mutant, as shown by the `display-report` command.
+    pub id: String,
+    /// The mutated function, using the `module::function` syntax.
+    pub function: String,
+    /// The outcome of the review.
+    pub state: TriageState,
+    /// The reason for the state.
+    pub reason: String,
+}
+
+/// The content of the triage file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TriageFile {
+    #[serde(default)]
+    mutants: Vec<TriageEntry>,
+}
+
+/// The reviewed mutants, keyed by their identity.
+///
+/// The triage file is written in TOML:
+/// ```toml
+/// [[mutants]]
+/// id = "5d1c3a6f0e4b2a97"
+/// function = "Fees::charge"
+/// state = "equivalent"
+/// reason = "The fee is rounded down to zero for such small amounts anyway."
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Triage {
+    entries: BTreeMap<String, TriageEntry>,
+}
+
+impl Triage {
+    /// Loads the triage file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("cannot read the triage file {}", path.display()))?;
+        let file: TriageFile = toml::from_str(&content)
+            .with_context(|| format!("invalid triage file {}", path.display()))?;
+
+        let mut entries = BTreeMap::new();
+        for entry in file.mutants {
+            if let Some(previous) = entries.insert(entry.id.clone(), entry) {
+                anyhow::bail!(
+                    "invalid triage file {}: mutant {} is listed more than once",
+                    path.display(),
+                    previous.id
+                );
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Returns the triage entry of the mutant with the given identity, if any.
+    pub fn get(&self, id: &str) -> Option<&TriageEntry> {
+        self.entries.get(id)
+    }
+
+    /// Returns all triaged mutants.
+    pub fn entries(&self) -> impl Iterator<Item = &TriageEntry> {
+        self.entries.values()
+    }
+}
+
+/// Computes the identity of the mutant from its diff.
+///
+/// The identity is built from the mutated file, the function and the lines changed by the mutant,
+/// so it doesn't change when unrelated lines are added or removed. It's used only for the reports
+/// which don't contain the identities computed by the mutator.
+pub fn mutant_id(file: &Path, module_func: &str, diff: &str) -> String {
+    // Use the same separator on all platforms.
+    let file = file
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    let mut fields = vec![file, module_func.into()];
+
+    match Patch::from_str(diff) {
+        Ok(patch) => {
+            for line in patch.hunks().iter().flat_map(|hunk| hunk.lines()) {
+                match line {
+                    Line::Delete(text) => fields.push(format!("-{text}").into()),
+                    Line::Insert(text) => fields.push(format!("+{text}").into()),
+                    Line::Context(_) => {},
+                }
+            }
+        },
+        Err(_) => fields.push(diff.into()),
+    }
+
+    stable_hash(fields.iter().map(|field| field.as_bytes()))
+}
+
+/// Loads the triage file and excludes the triaged mutants from the report.
+///
+/// If `triage_file` is not given, the [`TRIAGE_FILE`] in the package directory of the report is
+/// used if it exists. Warns about each triaged mutant which no longer exists in a function mutated
+/// in the report, the functions outside of the module and function filters are not checked.
+///
+/// Returns the loaded triage, if any.
+pub fn apply_triage_file(
+    report: &mut Report,
+    triage_file: Option<&Path>,
+) -> Result<Option<Triage>> {
+    let triage = match triage_file {
+        Some(path) => Triage::load(path)?,
+        None => {
+            let path = report.get_package_dir().join(TRIAGE_FILE);
+            if !path.is_file() {
+                return Ok(None);
+            }
+            Triage::load(&path)?
+        },
+    };
+
+    for id in report.apply_triage(&triage) {
+        warn!("the triaged mutant {id} no longer exists, remove it from the triage file");
+    }
+
+    Ok(Some(triage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{MiniReport, MutantStatus};
+    use std::path::PathBuf;
+
+    fn diff(original: &str, mutated: &str) -> String {
+        diffy::create_patch(original, mutated).to_string()
+    }
+
+    #[test]
+    fn mutant_id_does_not_depend_on_line_numbers() {
+        let file = Path::new("sources/Sum.move");
+        let id = mutant_id(file, "Sum::sum", &diff("x * y\n", "x + y\n"));
+
+        let shifted = mutant_id(
+            file,
+            "Sum::sum",
+            &diff("// comment\n\nx * y\n", "// comment\n\nx + y\n"),
+        );
+        assert_eq!(id, shifted);
+
+        assert_ne!(id, mutant_id(file, "Sum::sum", &diff("x * y\n", "x - y\n")));
+        assert_ne!(id, mutant_id(file, "Sum::mul", &diff("x * y\n", "x + y\n")));
+    }
+
+    #[test]
+    fn triaged_mutants_are_excluded_from_the_score() {
+        let path = PathBuf::from("sources/Sum.move");
+        let alive = [diff("x * y\n", "x + y\n"), diff("x * y\n", "x - y\n")];
+
+        let mut report = Report::new("package_dir".into());
+        for (status, diff) in [
+            (MutantStatus::KilledByTest, diff("x * y\n", "x / y\n")),
+            (MutantStatus::Alive, alive[0].clone()),
+            (MutantStatus::Alive, alive[1].clone()),
+        ] {
+            report.add_mutant_result(MiniReport::new(
+                path.clone(),
+                "Sum::sum".to_owned(),
+                status,
+                diff,
+            ));
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let triage_path = dir.path().join(TRIAGE_FILE);
+        let id = mutant_id(&path, "Sum::sum", &alive[0]);
+        fs::write(
+            &triage_path,
+            format!(
+                "[[mutants]]\nid = \"{id}\"\nfunction = \"Sum::sum\"\nstate = \"equivalent\"\nreason = \"same result\"\n\n\
+                 [[mutants]]\nid = \"0000000000000000\"\nfunction = \"Sum::sum\"\nstate = \"todo\"\nreason = \"removed\"\n\n\
+                 [[mutants]]\nid = \"1111111111111111\"\nfunction = \"Sum::mul\"\nstate = \"todo\"\nreason = \"not mutated\"\n"
+            ),
+        )
+        .unwrap();
+        let triage = Triage::load(&triage_path).unwrap();
+        assert_eq!(triage.get(&id).unwrap().state, TriageState::Equivalent);
+
+        // The function without any mutants in the report may be outside of the filters of the run.
+        let stale = report.apply_triage(&triage);
+        assert_eq!(stale, vec!["0000000000000000"]);
+        assert_eq!(report.mutants_tested(), 2);
+        assert_eq!(report.mutants_triaged(), 1);
+        assert_eq!(report.mutation_score(), 50.0);
+
+        let stat = &report.entries()[&path][0];
+        assert_eq!(stat.mutants_alive_diffs, vec![alive[1].clone()]);
+
+        // Applying the triage again gives the same result.
+        report.apply_triage(&triage);
+        assert_eq!(report.mutants_tested(), 2);
+        assert_eq!(report.mutants_triaged(), 1);
+
+        // Without the triage, all mutants are counted again.
+        report.apply_triage(&Triage::default());
+        assert_eq!(report.mutants_tested(), 3);
+        assert_eq!(report.mutants_triaged(), 0);
+    }
+
+    #[test]
+    fn stored_mutant_ids_are_preferred() {
+        let path = PathBuf::from("sources/Sum.move");
+        let legacy = diff("x * y\n", "x - y\n");
+
+        let mut report = Report::new("package_dir".into());
+        report.add_mutant_result(
+            MiniReport::new(
+                path.clone(),
+                "Sum::sum".to_owned(),
+                MutantStatus::Alive,
+                diff("x * y\n", "x + y\n"),
+            )
+            .with_mutant_id("0123456789abcdef".to_owned()),
+        );
+        report.add_mutant_result(MiniReport::new(
+            path.clone(),
+            "Sum::sum".to_owned(),
+            MutantStatus::Alive,
+            legacy.clone(),
+        ));
+
+        let stat = &report.entries()[&path][0];
+        let legacy_id = mutant_id(&path, "Sum::sum", &legacy);
+        assert_eq!(stat.alive_mutant_ids(&path), vec![
+            "0123456789abcdef".to_owned(),
+            legacy_id.clone()
+        ]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let triage_path = dir.path().join(TRIAGE_FILE);
+        fs::write(
+            &triage_path,
+            "[[mutants]]\nid = \"0123456789abcdef\"\nfunction = \"Sum::sum\"\nstate = \"ignored\"\nreason = \"\"\n",
+        )
+        .unwrap();
+        let triage = Triage::load(&triage_path).unwrap();
+
+        assert!(report.apply_triage(&triage).is_empty());
+        let stat = &report.entries()[&path][0];
+        assert_eq!(stat.mutants_triaged_ids, vec!["0123456789abcdef"]);
+        assert_eq!(stat.alive_mutant_ids(&path), vec![legacy_id]);
+    }
+
+    #[test]
+    fn duplicate_entries_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(TRIAGE_FILE);
+        let entry = "[[mutants]]\nid = \"a\"\nfunction = \"Sum::sum\"\nstate = \"ignored\"\nreason = \"\"\n";
+        fs::write(&path, format!("{entry}{entry}")).unwrap();
+        assert!(Triage::load(&path).is_err());
+
+        fs::write(
+            &path,
+            "[[mutants]]\nid = \"a\"\nfunction = \"Sum::sum\"\nstate = \"unknown\"\nreason = \"\"\n",
+        )
+        .unwrap();
+        assert!(Triage::load(&path).is_err());
+    }
+}